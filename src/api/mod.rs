@@ -1,2 +1,2 @@
 pub mod node;
-pub(super) mod sidecar;
+pub mod sidecar;
@@ -0,0 +1,204 @@
+use casper_types::{
+    execution::ExecutionResult, Block, BlockIdentifier, Transaction, TransactionHash,
+};
+use futures::{stream, Future, Stream, StreamExt, TryStreamExt};
+use l1_binary_port::RecordId;
+use std::{
+    ops::{Bound, RangeBounds},
+    time::Duration,
+};
+use tokio::time;
+
+use super::Client;
+use crate::api::node::binary::error::{BinaryError, NodeError, ProtocolError};
+
+/// Default number of blocks fetched concurrently.
+const DEFAULT_PARALLELISM: usize = 8;
+/// Default number of times a failed request is retried.
+const DEFAULT_MAX_RETRIES: usize = 3;
+/// Default delay before retrying a failed request.
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Options for fetching a range of blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockRangeOptions {
+    /// Maximum number of blocks, and of transactions within a block, fetched concurrently.
+    pub parallelism: usize,
+    /// Number of times a request failing with a transient error is retried.
+    pub max_retries: usize,
+    /// Delay before retrying a failed request.
+    pub retry_delay: Duration,
+    /// Whether to fetch each block's transactions.
+    pub include_transactions: bool,
+    /// Whether to fetch the execution result of each block's transactions.
+    pub include_execution_results: bool,
+}
+
+impl Default for BlockRangeOptions {
+    fn default() -> Self {
+        BlockRangeOptions {
+            parallelism: DEFAULT_PARALLELISM,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            include_transactions: false,
+            include_execution_results: false,
+        }
+    }
+}
+
+/// A block along with, if requested, its transactions and their execution results, in block
+/// order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FetchedBlock {
+    pub block: Block,
+    pub transactions: Option<Vec<Transaction>>,
+    pub execution_results: Option<Vec<ExecutionResult>>,
+}
+
+impl Client {
+    /// Streams blocks within a range of heights, in ascending order.
+    ///
+    /// The range is clamped to the node's available block range, as queried when the stream is
+    /// first polled, hence an unbounded range ends at the node's highest complete block.  Up to
+    /// `options.parallelism` blocks are fetched concurrently.  Requests failing with a transport
+    /// or throttling error, or for want of a record within the node's available range, are
+    /// retried, the stream yielding the error once retries are exhausted.  Other errors are
+    /// yielded straight away.
+    pub fn blocks(
+        &self,
+        range: impl RangeBounds<u64>,
+        options: BlockRangeOptions,
+    ) -> impl Stream<Item = Result<FetchedBlock, BinaryError>> + '_ {
+        let start = match range.start_bound() {
+            Bound::Included(height) => *height,
+            Bound::Excluded(height) => height.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(height) => height.saturating_add(1),
+            Bound::Excluded(height) => *height,
+            Bound::Unbounded => u64::MAX,
+        };
+        let parallelism = options.parallelism.max(1);
+
+        stream::once(with_retries(options, move || {
+            self.get().information().get_available_block_range()
+        }))
+        .map(move |available| match available {
+            Ok(available) => {
+                let heights =
+                    start.max(available.low())..end.min(available.high().saturating_add(1));
+                stream::iter(heights)
+                    .map(move |height| self.fetch_block(height, options))
+                    .buffered(parallelism)
+                    .left_stream()
+            }
+            Err(err) => stream::iter([Err(err)]).right_stream(),
+        })
+        .flatten()
+    }
+
+    async fn fetch_block(
+        &self,
+        height: u64,
+        options: BlockRangeOptions,
+    ) -> Result<FetchedBlock, BinaryError> {
+        let record = self.get().record();
+        let block = with_retries(options, || async {
+            record
+                .get_block(BlockIdentifier::Height(height))
+                .await?
+                .ok_or_else(|| missing_record(RecordId::BlockHeader, format!("height {height}")))
+        })
+        .await?;
+        let transaction_hashes: Vec<TransactionHash> = block.all_transaction_hashes().collect();
+        let parallelism = options.parallelism.max(1);
+
+        let transactions = if options.include_transactions {
+            let transactions = stream::iter(&transaction_hashes)
+                .map(|transaction_hash| {
+                    with_retries(options, move || async move {
+                        record
+                            .get_transaction(*transaction_hash)
+                            .await?
+                            .ok_or_else(|| {
+                                missing_record(RecordId::Transaction, transaction_hash.to_string())
+                            })
+                    })
+                })
+                .buffered(parallelism)
+                .try_collect()
+                .await?;
+            Some(transactions)
+        } else {
+            None
+        };
+
+        let execution_results = if options.include_execution_results {
+            let execution_results = stream::iter(&transaction_hashes)
+                .map(|transaction_hash| {
+                    with_retries(options, move || async move {
+                        record
+                            .get_execution_result(*transaction_hash)
+                            .await?
+                            .ok_or_else(|| {
+                                missing_record(
+                                    RecordId::ExecutionResult,
+                                    transaction_hash.to_string(),
+                                )
+                            })
+                    })
+                })
+                .buffered(parallelism)
+                .try_collect()
+                .await?;
+            Some(execution_results)
+        } else {
+            None
+        };
+
+        Ok(FetchedBlock {
+            block,
+            transactions,
+            execution_results,
+        })
+    }
+}
+
+/// Invokes `request`, retrying upon a transient error as per options.
+async fn with_retries<T, F, Fut>(
+    options: BlockRangeOptions,
+    mut request: F,
+) -> Result<T, BinaryError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, BinaryError>>,
+{
+    let mut retries = 0;
+    loop {
+        match request().await {
+            Err(err) if retries < options.max_retries && is_transient(&err) => {
+                retries += 1;
+                time::sleep(options.retry_delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Predicate: returns true if a request failing with the error may succeed if retried.
+///
+/// A record missing from the node's available range may yet be stored, whereas a response which
+/// cannot be decoded would be decoded the same way again.
+fn is_transient(err: &BinaryError) -> bool {
+    matches!(
+        err,
+        BinaryError::Transport(_)
+            | BinaryError::Protocol(ProtocolError::MissingRecord { .. })
+            | BinaryError::Node(NodeError::Throttled)
+    )
+}
+
+fn missing_record(record_id: RecordId, key: String) -> BinaryError {
+    ProtocolError::MissingRecord { record_id, key }.into()
+}
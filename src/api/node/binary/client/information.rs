@@ -0,0 +1,180 @@
+use casper_types::{
+    system::auction::DelegatorKind, AvailableBlockRange, BlockHeader, BlockIdentifier,
+    BlockSynchronizerStatus, ChainspecRawBytes, EraId, NextUpgrade, Peers, ProtocolVersion,
+    PublicKey, Timestamp, ValidatorChange,
+};
+use l1_binary_port::{
+    BinaryResponseAndRequest, Command, ConsensusStatus, ConsensusValidatorChanges, EraIdentifier,
+    InformationRequest, LastProgress, NetworkName, NodeStatus, ReactorStateName, RewardResponse,
+    Uptime,
+};
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use super::{parse_required_response, parse_response};
+use crate::api::node::binary::{error::BinaryError, proxy::Proxy};
+
+/// Client for "Get -> Information" queries, i.e. information about the node.
+#[derive(Clone)]
+pub struct GetInformationClient {
+    proxy: Arc<dyn Proxy>,
+}
+
+impl GetInformationClient {
+    pub(super) fn new(proxy: Arc<dyn Proxy>) -> Self {
+        GetInformationClient { proxy }
+    }
+
+    /// Returns header of identified block, or of the latest block if no identifier is specified.
+    pub async fn get_block_header(
+        &self,
+        block_id: Option<BlockIdentifier>,
+    ) -> Result<Option<BlockHeader>, BinaryError> {
+        let response = self
+            .dispatch(InformationRequest::BlockHeader(block_id))
+            .await?;
+        parse_response(&response)
+    }
+
+    /// Returns header of the latest switch block, i.e. the last block of an era.
+    pub async fn get_latest_switch_block_header(&self) -> Result<Option<BlockHeader>, BinaryError> {
+        let response = self
+            .dispatch(InformationRequest::LatestSwitchBlockHeader)
+            .await?;
+        parse_response(&response)
+    }
+
+    /// Returns status of the node.
+    pub async fn get_node_status(&self) -> Result<NodeStatus, BinaryError> {
+        let response = self.dispatch(InformationRequest::NodeStatus).await?;
+        parse_required_response(&response)
+    }
+
+    /// Returns peers to which the node is connected.
+    pub async fn get_peers(&self) -> Result<Peers, BinaryError> {
+        let response = self.dispatch(InformationRequest::Peers).await?;
+        parse_required_response(&response)
+    }
+
+    /// Returns time elapsed since the node started.
+    pub async fn get_uptime(&self) -> Result<Duration, BinaryError> {
+        let response = self.dispatch(InformationRequest::Uptime).await?;
+        parse_required_response::<Uptime>(&response)
+            .map(|uptime| Duration::from_secs(uptime.into_inner()))
+    }
+
+    /// Returns time at which the node last made progress.
+    pub async fn get_last_progress(&self) -> Result<Timestamp, BinaryError> {
+        let response = self.dispatch(InformationRequest::LastProgress).await?;
+        parse_required_response::<LastProgress>(&response).map(LastProgress::into_inner)
+    }
+
+    /// Returns name of the node's current reactor state, e.g. `Validate`.
+    pub async fn get_reactor_state(&self) -> Result<String, BinaryError> {
+        let response = self.dispatch(InformationRequest::ReactorState).await?;
+        parse_required_response::<ReactorStateName>(&response).map(ReactorStateName::into_inner)
+    }
+
+    /// Returns name of the network to which the node belongs.
+    pub async fn get_network_name(&self) -> Result<String, BinaryError> {
+        let response = self.dispatch(InformationRequest::NetworkName).await?;
+        parse_required_response::<NetworkName>(&response).map(NetworkName::into_inner)
+    }
+
+    /// Returns changes to each validator's status, by era.
+    pub async fn get_consensus_validator_changes(
+        &self,
+    ) -> Result<BTreeMap<PublicKey, Vec<(EraId, ValidatorChange)>>, BinaryError> {
+        let response = self
+            .dispatch(InformationRequest::ConsensusValidatorChanges)
+            .await?;
+        parse_required_response::<ConsensusValidatorChanges>(&response)
+            .map(ConsensusValidatorChanges::into_inner)
+    }
+
+    /// Returns status of the node's block synchronizer.
+    pub async fn get_block_synchronizer_status(
+        &self,
+    ) -> Result<BlockSynchronizerStatus, BinaryError> {
+        let response = self
+            .dispatch(InformationRequest::BlockSynchronizerStatus)
+            .await?;
+        parse_required_response(&response)
+    }
+
+    /// Returns range of contiguous blocks held by the node.
+    pub async fn get_available_block_range(&self) -> Result<AvailableBlockRange, BinaryError> {
+        let response = self
+            .dispatch(InformationRequest::AvailableBlockRange)
+            .await?;
+        parse_required_response(&response)
+    }
+
+    /// Returns next scheduled protocol upgrade, if any.
+    pub async fn get_next_upgrade(&self) -> Result<Option<NextUpgrade>, BinaryError> {
+        let response = self.dispatch(InformationRequest::NextUpgrade).await?;
+        parse_response(&response)
+    }
+
+    /// Returns consensus status, or `None` if the node is not a validator in the current era.
+    pub async fn get_consensus_status(&self) -> Result<Option<ConsensusStatus>, BinaryError> {
+        let response = self.dispatch(InformationRequest::ConsensusStatus).await?;
+        parse_response(&response)
+    }
+
+    /// Returns raw bytes of the chainspec, genesis accounts & global state configuration files.
+    pub async fn get_chainspec_raw_bytes(&self) -> Result<ChainspecRawBytes, BinaryError> {
+        let response = self.dispatch(InformationRequest::ChainspecRawBytes).await?;
+        parse_required_response(&response)
+    }
+
+    /// Returns protocol version of the node.
+    pub async fn get_protocol_version(&self) -> Result<ProtocolVersion, BinaryError> {
+        let response = self.dispatch(InformationRequest::ProtocolVersion).await?;
+        parse_required_response(&response)
+    }
+
+    /// Returns reward of a validator within identified era, or within the era of the latest
+    /// switch block if no identifier is specified.
+    pub async fn get_validator_reward(
+        &self,
+        era_identifier: Option<EraIdentifier>,
+        validator: PublicKey,
+    ) -> Result<Option<RewardResponse>, BinaryError> {
+        self.get_reward(era_identifier, validator, None).await
+    }
+
+    /// Returns reward of a delegator to a validator within identified era, or within the era of
+    /// the latest switch block if no identifier is specified.
+    pub async fn get_delegator_reward(
+        &self,
+        era_identifier: Option<EraIdentifier>,
+        validator: PublicKey,
+        delegator: DelegatorKind,
+    ) -> Result<Option<RewardResponse>, BinaryError> {
+        self.get_reward(era_identifier, validator, Some(delegator))
+            .await
+    }
+
+    async fn get_reward(
+        &self,
+        era_identifier: Option<EraIdentifier>,
+        validator: PublicKey,
+        delegator: Option<DelegatorKind>,
+    ) -> Result<Option<RewardResponse>, BinaryError> {
+        let response = self
+            .dispatch(InformationRequest::Reward {
+                era_identifier,
+                validator: Box::new(validator),
+                delegator: delegator.map(Box::new),
+            })
+            .await?;
+        parse_response(&response)
+    }
+
+    async fn dispatch(
+        &self,
+        req: InformationRequest,
+    ) -> Result<BinaryResponseAndRequest, BinaryError> {
+        self.proxy.dispatch(Command::Get(req.try_into()?)).await
+    }
+}
@@ -1,13 +1,187 @@
-pub struct GetInformationClient {}
+mod blocks;
+mod information;
+mod record;
+mod state;
+mod trie;
 
-impl GetInformationClient {
-    async fn get_block_header(&self) -> Result<BinaryResponseAndRequest, Error>;
-}
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    Transaction,
+};
+use l1_binary_port::{
+    BinaryResponseAndRequest, Command, PayloadEntity, SpeculativeExecutionResult,
+};
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+
+use super::{
+    error::{BinaryError, ProtocolError},
+    proxy::Proxy,
+};
+
+pub use blocks::{BlockRangeOptions, FetchedBlock};
+pub use information::GetInformationClient;
+pub use record::GetRecordClient;
+pub use state::GetStateClient;
 
 pub struct GetClient {
     information: GetInformationClient,
+    record: GetRecordClient,
+    state: GetStateClient,
+}
+
+impl GetClient {
+    pub fn information(&self) -> &GetInformationClient {
+        &self.information
+    }
+
+    pub fn record(&self) -> &GetRecordClient {
+        &self.record
+    }
+
+    pub fn state(&self) -> &GetStateClient {
+        &self.state
+    }
 }
 
 pub struct Client {
+    proxy: Arc<dyn Proxy>,
     get: GetClient,
 }
+
+impl Client {
+    pub fn new(proxy: Arc<dyn Proxy>) -> Self {
+        let information = GetInformationClient::new(proxy.clone());
+        Client {
+            get: GetClient {
+                record: GetRecordClient::new(proxy.clone(), information.clone()),
+                state: GetStateClient::new(proxy.clone()),
+                information,
+            },
+            proxy,
+        }
+    }
+
+    pub fn get(&self) -> &GetClient {
+        &self.get
+    }
+
+    /// Submits a transaction (V1 or legacy deploy) to the node for inclusion within a block.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "binary_try_accept_transaction",
+            skip_all,
+            fields(transaction_hash = %transaction.hash()),
+            err
+        )
+    )]
+    pub async fn try_accept_transaction(
+        &self,
+        transaction: Transaction,
+    ) -> Result<(), BinaryError> {
+        let response = self
+            .proxy
+            .dispatch(Command::TryAcceptTransaction { transaction })
+            .await?;
+        check_transaction_response(&response)
+    }
+
+    /// Executes a transaction against the latest block's global state without committing its
+    /// effects.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "binary_try_speculative_exec",
+            skip_all,
+            fields(transaction_hash = %transaction.hash()),
+            err
+        )
+    )]
+    pub async fn try_speculative_exec(
+        &self,
+        transaction: Transaction,
+    ) -> Result<SpeculativeExecutionResult, BinaryError> {
+        let response = self
+            .proxy
+            .dispatch(Command::TrySpeculativeExec { transaction })
+            .await?;
+        check_transaction_response(&response)?;
+        parse_required_response(&response)
+    }
+}
+
+/// Maps a failed response to a transaction command to the reason for rejection.
+fn check_transaction_response(response: &BinaryResponseAndRequest) -> Result<(), BinaryError> {
+    if response.is_success() {
+        return Ok(());
+    }
+    Err(BinaryError::from_error_code(response.error_code()))
+}
+
+/// Returns type tag & payload of a successful response, or `None` if empty or not found.
+fn read_payload(response: &BinaryResponseAndRequest) -> Result<Option<(u8, &[u8])>, BinaryError> {
+    let response = response.response();
+    if response.is_not_found() {
+        return Ok(None);
+    }
+    if !response.is_success() {
+        return Err(BinaryError::from_error_code(response.error_code()));
+    }
+    Ok(response
+        .returned_data_type_tag()
+        .map(|tag| (tag, response.payload())))
+}
+
+/// Decodes a response payload, mapping an empty or not found response to `None`.
+fn parse_response<T>(response: &BinaryResponseAndRequest) -> Result<Option<T>, BinaryError>
+where
+    T: FromBytes + PayloadEntity,
+{
+    match read_payload(response)? {
+        None => Ok(None),
+        Some((tag, payload)) if tag == T::RESPONSE_TYPE as u8 => {
+            Ok(Some(bytesrepr::deserialize_from_slice(payload)?))
+        }
+        Some((tag, _)) => Err(ProtocolError::UnexpectedResponseType {
+            expected: T::RESPONSE_TYPE,
+            got: Some(tag),
+        }
+        .into()),
+    }
+}
+
+/// Decodes a response payload which the node always returns, mapping an empty or not found
+/// response to an error.
+fn parse_required_response<T>(response: &BinaryResponseAndRequest) -> Result<T, BinaryError>
+where
+    T: FromBytes + PayloadEntity,
+{
+    parse_response(response)?.ok_or_else(|| {
+        ProtocolError::UnexpectedResponseType {
+            expected: T::RESPONSE_TYPE,
+            got: None,
+        }
+        .into()
+    })
+}
+
+/// Decodes a response payload which may be of either current or legacy type, mapping an empty or
+/// not found response to `None`.
+///
+/// Legacy (pre 2.0) records are returned as held within the node's legacy storage, i.e.
+/// bincode-encoded.
+fn parse_response_or_legacy<T, L>(
+    response: &BinaryResponseAndRequest,
+) -> Result<Option<T>, BinaryError>
+where
+    T: FromBytes + PayloadEntity + From<L>,
+    L: DeserializeOwned + PayloadEntity,
+{
+    match read_payload(response)? {
+        Some((tag, payload)) if tag == L::RESPONSE_TYPE as u8 => {
+            Ok(Some(T::from(bincode::deserialize::<L>(payload)?)))
+        }
+        _ => parse_response(response),
+    }
+}
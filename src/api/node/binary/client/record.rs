@@ -0,0 +1,165 @@
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    execution::{ExecutionResult, ExecutionResultV1},
+    Block, BlockBody, BlockBodyV1, BlockHash, BlockHeader, BlockHeaderV1, BlockIdentifier,
+    BlockSignatures, BlockSignaturesV1, Deploy, Transaction, TransactionHash, Transfer, TransferV1,
+};
+use l1_binary_port::{BinaryResponseAndRequest, Command, GetRequest, PayloadEntity, RecordId};
+use std::sync::Arc;
+
+use super::{parse_response_or_legacy, read_payload, GetInformationClient};
+use crate::api::node::binary::{
+    error::{BinaryError, ProtocolError},
+    proxy::Proxy,
+    types::{ApprovalsHashes, ApprovalsHashesV1},
+};
+
+/// Client for "Get -> Record" queries, i.e. historical data structures.
+///
+/// Blocks may be identified by hash or height, the latter being resolved to a hash via an
+/// information query.  Records which the node does not hold are returned as `None`.
+pub struct GetRecordClient {
+    proxy: Arc<dyn Proxy>,
+    information: GetInformationClient,
+}
+
+impl GetRecordClient {
+    pub(super) fn new(proxy: Arc<dyn Proxy>, information: GetInformationClient) -> Self {
+        GetRecordClient { proxy, information }
+    }
+
+    /// Returns header of identified block.
+    pub async fn get_block_header(
+        &self,
+        block_id: BlockIdentifier,
+    ) -> Result<Option<BlockHeader>, BinaryError> {
+        match block_id {
+            BlockIdentifier::Hash(block_hash) => {
+                let response = self.dispatch(RecordId::BlockHeader, &block_hash).await?;
+                parse_response_or_legacy::<BlockHeader, BlockHeaderV1>(&response)
+            }
+            BlockIdentifier::Height(_) => self.information.get_block_header(Some(block_id)).await,
+        }
+    }
+
+    /// Returns identified block, i.e. its header and body.
+    pub async fn get_block(&self, block_id: BlockIdentifier) -> Result<Option<Block>, BinaryError> {
+        let Some(header) = self.get_block_header(block_id).await? else {
+            return Ok(None);
+        };
+        let response = self
+            .dispatch(RecordId::BlockBody, header.body_hash())
+            .await?;
+        let Some(body) = parse_response_or_legacy::<BlockBody, BlockBodyV1>(&response)? else {
+            return Ok(None);
+        };
+        Ok(Some(Block::new_from_header_and_body(header, body)?))
+    }
+
+    /// Returns hashes of the approvals of each transaction within identified block.
+    pub async fn get_approvals_hashes(
+        &self,
+        block_id: BlockIdentifier,
+    ) -> Result<Option<ApprovalsHashes>, BinaryError> {
+        let Some(block_hash) = self.resolve_block_hash(block_id).await? else {
+            return Ok(None);
+        };
+        let response = self
+            .dispatch(RecordId::ApprovalsHashes, &block_hash)
+            .await?;
+        parse_response_or_legacy::<ApprovalsHashes, ApprovalsHashesV1>(&response)
+    }
+
+    /// Returns finality signatures of identified block.
+    pub async fn get_finality_signatures(
+        &self,
+        block_id: BlockIdentifier,
+    ) -> Result<Option<BlockSignatures>, BinaryError> {
+        let Some(block_hash) = self.resolve_block_hash(block_id).await? else {
+            return Ok(None);
+        };
+        let response = self.dispatch(RecordId::BlockMetadata, &block_hash).await?;
+        parse_response_or_legacy::<BlockSignatures, BlockSignaturesV1>(&response)
+    }
+
+    /// Returns transfers executed within identified block.
+    ///
+    /// Current & legacy transfers share a response type, legacy transfers being those of legacy
+    /// blocks, returned bincode-encoded.
+    pub async fn get_transfers(
+        &self,
+        block_id: BlockIdentifier,
+    ) -> Result<Option<Vec<Transfer>>, BinaryError> {
+        let Some(header) = self.get_block_header(block_id).await? else {
+            return Ok(None);
+        };
+        let response = self
+            .dispatch(RecordId::Transfer, &header.block_hash())
+            .await?;
+        let Some((tag, payload)) = read_payload(&response)? else {
+            return Ok(None);
+        };
+        if tag != Vec::<Transfer>::RESPONSE_TYPE as u8 {
+            return Err(ProtocolError::UnexpectedResponseType {
+                expected: Vec::<Transfer>::RESPONSE_TYPE,
+                got: Some(tag),
+            }
+            .into());
+        }
+        match header {
+            BlockHeader::V1(_) => {
+                let transfers: Vec<TransferV1> = bincode::deserialize(payload)?;
+                Ok(Some(transfers.into_iter().map(Transfer::from).collect()))
+            }
+            BlockHeader::V2(_) => Ok(Some(bytesrepr::deserialize_from_slice(payload)?)),
+        }
+    }
+
+    /// Returns identified transaction.
+    pub async fn get_transaction(
+        &self,
+        transaction_hash: TransactionHash,
+    ) -> Result<Option<Transaction>, BinaryError> {
+        let response = self
+            .dispatch(RecordId::Transaction, &transaction_hash)
+            .await?;
+        parse_response_or_legacy::<Transaction, Deploy>(&response)
+    }
+
+    /// Returns result of executing identified transaction.
+    pub async fn get_execution_result(
+        &self,
+        transaction_hash: TransactionHash,
+    ) -> Result<Option<ExecutionResult>, BinaryError> {
+        let response = self
+            .dispatch(RecordId::ExecutionResult, &transaction_hash)
+            .await?;
+        parse_response_or_legacy::<ExecutionResult, ExecutionResultV1>(&response)
+    }
+
+    async fn resolve_block_hash(
+        &self,
+        block_id: BlockIdentifier,
+    ) -> Result<Option<BlockHash>, BinaryError> {
+        match block_id {
+            BlockIdentifier::Hash(block_hash) => Ok(Some(block_hash)),
+            BlockIdentifier::Height(_) => Ok(self
+                .information
+                .get_block_header(Some(block_id))
+                .await?
+                .map(|header| header.block_hash())),
+        }
+    }
+
+    async fn dispatch(
+        &self,
+        record_id: RecordId,
+        key: &impl ToBytes,
+    ) -> Result<BinaryResponseAndRequest, BinaryError> {
+        let req = GetRequest::Record {
+            record_type_tag: record_id.into(),
+            key: key.to_bytes()?,
+        };
+        self.proxy.dispatch(Command::Get(req)).await
+    }
+}
@@ -0,0 +1,268 @@
+use casper_types::{
+    addressable_entity::NamedKeyAddr, bytesrepr::Bytes, Digest, GlobalStateIdentifier, Key, KeyTag,
+    StoredValue,
+};
+use l1_binary_port::{
+    BalanceResponse, BinaryResponseAndRequest, Command, DictionaryItemIdentifier,
+    DictionaryQueryResult, GetRequest, GetTrieFullResult, GlobalStateEntityQualifier,
+    GlobalStateQueryResult, GlobalStateRequest, KeyPrefix, PurseIdentifier,
+};
+use std::sync::Arc;
+
+use super::parse_response;
+use crate::{
+    api::node::binary::{
+        error::{BinaryError, ProtocolError},
+        proxy::Proxy,
+    },
+    crypto::{merkle, ProofError},
+};
+
+/// Maximum number of redirects followed when resolving a named key, an account migrated to an
+/// addressable entity redirecting once.
+const MAX_NAMED_KEY_REDIRECTS: usize = 1;
+
+/// Client for "Get -> State" queries, i.e. data from global state.
+///
+/// Queries are made against the state identified by block hash, block height or state root hash,
+/// or against the state of the latest block if no identifier is specified.
+#[derive(Clone)]
+pub struct GetStateClient {
+    proxy: Arc<dyn Proxy>,
+}
+
+impl GetStateClient {
+    pub(super) fn new(proxy: Arc<dyn Proxy>) -> Self {
+        GetStateClient { proxy }
+    }
+
+    /// Returns value stored under a key, following a path of named keys, together with a proof.
+    pub async fn get_item(
+        &self,
+        state_id: Option<GlobalStateIdentifier>,
+        base_key: Key,
+        path: Vec<String>,
+    ) -> Result<Option<GlobalStateQueryResult>, BinaryError> {
+        let qualifier = GlobalStateEntityQualifier::Item { base_key, path };
+        let response = self.dispatch(state_id, qualifier).await?;
+        parse_response(&response)
+    }
+
+    /// Returns all values stored under keys of a given type.
+    pub async fn get_all_items(
+        &self,
+        state_id: Option<GlobalStateIdentifier>,
+        key_tag: KeyTag,
+    ) -> Result<Vec<StoredValue>, BinaryError> {
+        let qualifier = GlobalStateEntityQualifier::AllItems { key_tag };
+        let response = self.dispatch(state_id, qualifier).await?;
+        Ok(parse_response(&response)?.unwrap_or_default())
+    }
+
+    /// Returns all values stored under keys sharing a prefix.
+    pub async fn get_items_by_prefix(
+        &self,
+        state_id: Option<GlobalStateIdentifier>,
+        key_prefix: KeyPrefix,
+    ) -> Result<Vec<StoredValue>, BinaryError> {
+        let qualifier = GlobalStateEntityQualifier::ItemsByPrefix { key_prefix };
+        let response = self.dispatch(state_id, qualifier).await?;
+        Ok(parse_response(&response)?.unwrap_or_default())
+    }
+
+    /// Returns balance of a purse, identified directly or via its owner, together with any
+    /// active holds upon it.
+    pub async fn get_balance(
+        &self,
+        state_id: Option<GlobalStateIdentifier>,
+        purse_identifier: PurseIdentifier,
+    ) -> Result<Option<BalanceResponse>, BinaryError> {
+        let qualifier = GlobalStateEntityQualifier::Balance { purse_identifier };
+        let response = self.dispatch(state_id, qualifier).await?;
+        parse_response(&response)
+    }
+
+    /// Returns a dictionary item together with its key.
+    pub async fn get_dictionary_item(
+        &self,
+        state_id: Option<GlobalStateIdentifier>,
+        identifier: DictionaryItemIdentifier,
+    ) -> Result<Option<DictionaryQueryResult>, BinaryError> {
+        let qualifier = GlobalStateEntityQualifier::DictionaryItem { identifier };
+        let response = self.dispatch(state_id, qualifier).await?;
+        parse_response(&response)
+    }
+
+    /// Returns value stored under a key, following a path of named keys, within the state
+    /// identified by a trusted state root hash, verifying the proof returned by the node.
+    pub async fn get_verified_item(
+        &self,
+        state_root_hash: Digest,
+        base_key: Key,
+        path: Vec<String>,
+    ) -> Result<Option<StoredValue>, BinaryError> {
+        let state_id = GlobalStateIdentifier::StateRootHash(state_root_hash);
+        let Some(result) = self
+            .get_item(Some(state_id), base_key, path.clone())
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(merkle::verify_query_result(
+            result,
+            &base_key,
+            &path,
+            &state_root_hash,
+        )?))
+    }
+
+    /// Returns a dictionary item together with its key within the state identified by a trusted
+    /// state root hash, verifying the proof returned by the node.
+    ///
+    /// The item's key is derived from the identifier, a dictionary named within an account,
+    /// contract or addressable entity being resolved via a verified query.
+    pub async fn get_verified_dictionary_item(
+        &self,
+        state_root_hash: Digest,
+        identifier: DictionaryItemIdentifier,
+    ) -> Result<Option<(Key, StoredValue)>, BinaryError> {
+        let state_id = GlobalStateIdentifier::StateRootHash(state_root_hash);
+        let Some(result) = self
+            .get_dictionary_item(Some(state_id), identifier.clone())
+            .await?
+        else {
+            return Ok(None);
+        };
+        let dictionary_key = self
+            .get_verified_dictionary_key(state_root_hash, identifier)
+            .await?;
+        Ok(Some(merkle::verify_dictionary_query_result(
+            result,
+            &dictionary_key,
+            &state_root_hash,
+        )?))
+    }
+
+    /// Derives key of an identified dictionary item, resolving the dictionary's seed URef within
+    /// the named keys of its owner as of a trusted state root hash.
+    async fn get_verified_dictionary_key(
+        &self,
+        state_root_hash: Digest,
+        identifier: DictionaryItemIdentifier,
+    ) -> Result<Key, BinaryError> {
+        let (owner_key, dictionary_name, dictionary_item_key) = match identifier {
+            DictionaryItemIdentifier::URef {
+                seed_uref,
+                dictionary_item_key,
+            } => return Ok(Key::dictionary(seed_uref, dictionary_item_key.as_bytes())),
+            DictionaryItemIdentifier::DictionaryItem(dictionary_addr) => {
+                return Ok(Key::Dictionary(dictionary_addr))
+            }
+            DictionaryItemIdentifier::AccountNamedKey {
+                hash,
+                dictionary_name,
+                dictionary_item_key,
+            } => (Key::Account(hash), dictionary_name, dictionary_item_key),
+            DictionaryItemIdentifier::ContractNamedKey {
+                hash,
+                dictionary_name,
+                dictionary_item_key,
+            } => (Key::Hash(hash), dictionary_name, dictionary_item_key),
+            DictionaryItemIdentifier::EntityNamedKey {
+                addr,
+                dictionary_name,
+                dictionary_item_key,
+            } => {
+                let named_key_addr = NamedKeyAddr::new_from_string(addr, dictionary_name.clone())?;
+                (
+                    Key::NamedKey(named_key_addr),
+                    dictionary_name,
+                    dictionary_item_key,
+                )
+            }
+        };
+        // Node answered with an item, hence claims the owner exists.
+        let owner = self
+            .get_verified_item(state_root_hash, owner_key, vec![])
+            .await?
+            .ok_or_else(|| ProofError::UnresolvedName {
+                key: Box::new(owner_key),
+                name: dictionary_name.clone(),
+            })?;
+        Ok(merkle::named_dictionary_key(
+            &owner_key,
+            &owner,
+            &dictionary_name,
+            &dictionary_item_key,
+        )?)
+    }
+
+    /// Returns key stored under a name within the named keys of an account, contract or
+    /// addressable entity.
+    ///
+    /// An account migrated to an addressable entity is followed to the entity, failing with
+    /// `ProtocolError::TooManyRedirects` if redirected further.
+    pub async fn get_named_key(
+        &self,
+        state_id: Option<GlobalStateIdentifier>,
+        base_key: Key,
+        name: &str,
+    ) -> Result<Option<Key>, BinaryError> {
+        let mut base_key = base_key;
+        let mut redirects = 0;
+        loop {
+            let Some(result) = self.get_item(state_id, base_key, vec![]).await? else {
+                return Ok(None);
+            };
+            match (result.into_inner().0, base_key) {
+                (StoredValue::Account(account), _) => {
+                    return Ok(account.named_keys().get(name).copied())
+                }
+                (StoredValue::Contract(contract), _) => {
+                    return Ok(contract.named_keys().get(name).copied())
+                }
+                (StoredValue::AddressableEntity(_), Key::AddressableEntity(entity_addr)) => {
+                    let key = Key::NamedKey(NamedKeyAddr::new_from_string(
+                        entity_addr,
+                        name.to_string(),
+                    )?);
+                    return match self.get_item(state_id, key, vec![]).await? {
+                        Some(result) => match result.into_inner().0 {
+                            StoredValue::NamedKey(named_key) => Ok(Some(named_key.get_key()?)),
+                            _ => Ok(None),
+                        },
+                        None => Ok(None),
+                    };
+                }
+                (StoredValue::CLValue(value), Key::Account(_)) => {
+                    if redirects == MAX_NAMED_KEY_REDIRECTS {
+                        return Err(ProtocolError::TooManyRedirects(base_key).into());
+                    }
+                    redirects += 1;
+                    base_key = value.into_t()?;
+                }
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    /// Returns raw bytes of a global state trie node, if held by the node.
+    pub async fn get_trie(&self, trie_key: Digest) -> Result<Option<Bytes>, BinaryError> {
+        let response = self
+            .proxy
+            .dispatch(Command::Get(GetRequest::Trie { trie_key }))
+            .await?;
+        Ok(parse_response::<GetTrieFullResult>(&response)?.and_then(GetTrieFullResult::into_inner))
+    }
+
+    async fn dispatch(
+        &self,
+        state_id: Option<GlobalStateIdentifier>,
+        qualifier: GlobalStateEntityQualifier,
+    ) -> Result<BinaryResponseAndRequest, BinaryError> {
+        let req = GlobalStateRequest::new(state_id, qualifier);
+        self.proxy
+            .dispatch(Command::Get(GetRequest::State(Box::new(req))))
+            .await
+    }
+}
@@ -0,0 +1,96 @@
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    Digest, Key, KeyTag, StoredValue,
+};
+use futures::{stream, Stream};
+
+use super::GetStateClient;
+use crate::api::node::binary::{
+    error::{BinaryError, ProtocolError},
+    types::Trie,
+};
+
+impl GetStateClient {
+    /// Returns global state trie identified by its hash, verifying that it hashes as such.
+    pub async fn get_trie_node(&self, trie_key: Digest) -> Result<Option<Trie>, BinaryError> {
+        let Some(bytes) = self.get_trie(trie_key).await? else {
+            return Ok(None);
+        };
+        let hash = Digest::hash_into_chunks_if_necessary(&bytes);
+        if hash != trie_key {
+            return Err(ProtocolError::TrieHashMismatch {
+                expected: trie_key,
+                got: hash,
+            }
+            .into());
+        }
+        Ok(Some(bytesrepr::deserialize_from_slice(bytes)?))
+    }
+
+    /// Streams entries of the global state under a state root hash, in key order, whose
+    /// serialized keys start with a prefix.
+    ///
+    /// The trie is walked depth first, one trie at a time, skipping subtries whose keys cannot
+    /// start with the prefix.  The stream ends upon the first error, e.g. a trie missing from the
+    /// node.
+    pub fn walk_trie(
+        &self,
+        state_root_hash: Digest,
+        prefix: Vec<u8>,
+    ) -> impl Stream<Item = Result<(Key, StoredValue), BinaryError>> + '_ {
+        // Tries yet to be walked, along with the key bytes leading to each.
+        let pending = vec![(state_root_hash, vec![])];
+        stream::try_unfold(pending, move |mut pending| {
+            let prefix = prefix.clone();
+            async move {
+                while let Some((trie_key, path)) = pending.pop() {
+                    let trie = self
+                        .get_trie_node(trie_key)
+                        .await?
+                        .ok_or(ProtocolError::MissingTrie(trie_key))?;
+                    match trie {
+                        Trie::Leaf { key, value } => {
+                            if key.to_bytes()?.starts_with(&prefix) {
+                                return Ok(Some(((key, *value), pending)));
+                            }
+                        }
+                        Trie::Node { pointers } => {
+                            // Pushed in reverse, so children are walked in key order.
+                            for (index, pointer) in pointers.into_iter().rev() {
+                                let mut child_path = path.clone();
+                                child_path.push(index);
+                                if may_hold_prefix(&child_path, &prefix) {
+                                    pending.push((pointer.into_hash(), child_path));
+                                }
+                            }
+                        }
+                        Trie::Extension { affix, pointer } => {
+                            let mut child_path = path;
+                            child_path.extend_from_slice(&affix);
+                            if may_hold_prefix(&child_path, &prefix) {
+                                pending.push((pointer.into_hash(), child_path));
+                            }
+                        }
+                    }
+                }
+                Ok(None)
+            }
+        })
+    }
+
+    /// Streams entries of the global state under a state root hash whose keys are of a type,
+    /// e.g. `KeyTag::Balance`.
+    pub fn walk_trie_by_tag(
+        &self,
+        state_root_hash: Digest,
+        key_tag: KeyTag,
+    ) -> impl Stream<Item = Result<(Key, StoredValue), BinaryError>> + '_ {
+        self.walk_trie(state_root_hash, vec![key_tag as u8])
+    }
+}
+
+/// Predicate: returns true if keys beneath a trie, reached via `path`, may start with `prefix`.
+fn may_hold_prefix(path: &[u8], prefix: &[u8]) -> bool {
+    let len = path.len().min(prefix.len());
+    path[..len] == prefix[..len]
+}
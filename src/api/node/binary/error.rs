@@ -0,0 +1,396 @@
+use casper_types::{
+    bytesrepr, BlockHash, BlockIdentifier, BlockValidationError, CLValueError, Digest, EraId, Key,
+};
+use l1_binary_port::{ErrorCode, RecordId, ResponseType};
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+use crate::crypto::{ChainError, ProofError};
+
+/// Error arising from interaction with a node's binary port.
+#[derive(Error, Debug)]
+pub enum BinaryError {
+    /// Node could not be reached, or did not answer in time.
+    #[error("Transport error: {0}")]
+    Transport(#[from] TransportError),
+
+    /// Node's response could not be understood.
+    #[error("Protocol error: {0}")]
+    Protocol(#[from] ProtocolError),
+
+    /// Node understood but declined the request.
+    #[error("Node error: {0}")]
+    Node(#[from] NodeError),
+}
+
+impl BinaryError {
+    /// Maps an error code returned by the node.
+    pub fn from_error_code(error_code: u16) -> Self {
+        BinaryError::Node(NodeError::from_error_code(error_code))
+    }
+}
+
+impl From<l1_binary_port::Error> for BinaryError {
+    fn from(err: l1_binary_port::Error) -> Self {
+        match err {
+            l1_binary_port::Error::Io(err) => TransportError::ConnectionError(err).into(),
+            err => ProtocolError::MessageError(err).into(),
+        }
+    }
+}
+
+impl From<bytesrepr::Error> for BinaryError {
+    fn from(err: bytesrepr::Error) -> Self {
+        ProtocolError::SerializationError(err).into()
+    }
+}
+
+impl From<bincode::Error> for BinaryError {
+    fn from(err: bincode::Error) -> Self {
+        ProtocolError::LegacySerializationError(err).into()
+    }
+}
+
+impl From<Box<BlockValidationError>> for BinaryError {
+    fn from(err: Box<BlockValidationError>) -> Self {
+        ProtocolError::InvalidBlock(err).into()
+    }
+}
+
+impl From<ProofError> for BinaryError {
+    fn from(err: ProofError) -> Self {
+        ProtocolError::InvalidProof(Box::new(err)).into()
+    }
+}
+
+impl From<CLValueError> for BinaryError {
+    fn from(err: CLValueError) -> Self {
+        ProtocolError::InvalidValue(err).into()
+    }
+}
+
+/// Error whilst exchanging messages with the node.
+#[derive(Error, Debug)]
+pub enum TransportError {
+    #[error("Failed to connect to binary port: {0}")]
+    ConnectionError(#[source] std::io::Error),
+
+    #[error("Binary port connection closed by node")]
+    ConnectionClosed,
+
+    #[error("Timed out waiting for access to binary port client")]
+    ClientAccessTimeout,
+
+    #[error("Timed out waiting for binary port message transfer")]
+    MessageTimeout,
+
+    #[error("Binary port proxy has shut down")]
+    ProxyShutdown,
+}
+
+/// Error whilst encoding a request or decoding a response.
+#[derive(Error, Debug)]
+pub enum ProtocolError {
+    #[error("Binary message error: {0}")]
+    MessageError(#[source] l1_binary_port::Error),
+
+    #[error("Serialization error: {0}")]
+    SerializationError(#[source] bytesrepr::Error),
+
+    /// Legacy (pre 2.0) record, which the node returns bincode-encoded, could not be decoded.
+    #[error("Legacy record serialization error: {0}")]
+    LegacySerializationError(#[source] bincode::Error),
+
+    #[error("Response does not match request: expected id {expected}, got id {got}")]
+    RequestIdMismatch { expected: u16, got: u16 },
+
+    #[error("Unexpected response type: expected {expected}, got tag {got:?}")]
+    UnexpectedResponseType {
+        expected: ResponseType,
+        got: Option<u8>,
+    },
+
+    #[error("Invalid CL value: {0}")]
+    InvalidValue(CLValueError),
+
+    #[error("Invalid block: {0}")]
+    InvalidBlock(#[source] Box<BlockValidationError>),
+
+    /// Node lacks a record which it claims to hold, e.g. a block within its available range.
+    #[error("Missing {record_id} record: {key}")]
+    MissingRecord { record_id: RecordId, key: String },
+
+    /// Node lacks a global state trie beneath a state root hash which it holds.
+    #[error("Missing trie {0}")]
+    MissingTrie(Digest),
+
+    /// Trie returned by the node does not hash to the requested key.
+    #[error("Trie hash mismatch: expected {expected}, got {got}")]
+    TrieHashMismatch { expected: Digest, got: Digest },
+
+    /// Account's named keys redirect, via values stored under account keys, more times than a
+    /// migrated account may.
+    #[error("Too many redirects resolving named keys of {0}")]
+    TooManyRedirects(Key),
+
+    /// Merkle proof accompanying a global state value does not hold.
+    #[error("Invalid merkle proof: {0}")]
+    InvalidProof(#[source] Box<ProofError>),
+}
+
+/// Error reported by the node via a response's error code.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeError {
+    /// Requested function is disabled within node's configuration.
+    #[error("function disabled by node")]
+    FunctionDisabled,
+
+    /// Requested data, or data required to process the request, was not found.
+    #[error("not found: {0}")]
+    NotFound(ErrorCode),
+
+    /// Request is malformed.
+    #[error("bad request: {0}")]
+    BadRequest(ErrorCode),
+
+    /// Request is not supported by the node's version.
+    #[error("unsupported request: {0}")]
+    UnsupportedRequest(ErrorCode),
+
+    /// Node is throttling requests.
+    #[error("request throttled by node")]
+    Throttled,
+
+    /// Global state query failed.
+    #[error("global state query failed")]
+    QueryFailed,
+
+    /// Node failed to process the request.
+    #[error("internal node error")]
+    Internal,
+
+    /// Wasm of a transaction failed preprocessing.
+    #[error("wasm preprocessing failed")]
+    WasmPreprocessing,
+
+    /// Current gas price exceeds the transaction's gas price tolerance.
+    #[error("gas price too high: {0}")]
+    GasPriceTooHigh(ErrorCode),
+
+    /// Transaction is invalid, e.g. excessive time to live or invalid approval.
+    #[error("invalid transaction: {0}")]
+    InvalidTransaction(ErrorCode),
+
+    /// Transaction is valid but cannot be executed against global state, e.g. insufficient
+    /// balance or unknown entry point.
+    #[error("transaction not executable: {0}")]
+    TransactionNotExecutable(ErrorCode),
+
+    /// Error code unknown to this version of the SDK.
+    #[error("unknown error code: {0}")]
+    Unknown(u16),
+}
+
+impl NodeError {
+    /// Maps an error code returned by the node.
+    pub fn from_error_code(error_code: u16) -> Self {
+        match ErrorCode::try_from(error_code) {
+            Ok(error_code) => NodeError::from(error_code),
+            Err(_) => NodeError::Unknown(error_code),
+        }
+    }
+
+    /// Returns the node's error code.
+    pub fn error_code(&self) -> u16 {
+        match self {
+            NodeError::FunctionDisabled => ErrorCode::FunctionDisabled as u16,
+            NodeError::Throttled => ErrorCode::RequestThrottled as u16,
+            NodeError::QueryFailed => ErrorCode::FailedQuery as u16,
+            NodeError::Internal => ErrorCode::InternalError as u16,
+            NodeError::WasmPreprocessing => ErrorCode::WasmPreprocessing as u16,
+            NodeError::NotFound(error_code)
+            | NodeError::BadRequest(error_code)
+            | NodeError::UnsupportedRequest(error_code)
+            | NodeError::GasPriceTooHigh(error_code)
+            | NodeError::InvalidTransaction(error_code)
+            | NodeError::TransactionNotExecutable(error_code) => *error_code as u16,
+            NodeError::Unknown(error_code) => *error_code,
+        }
+    }
+}
+
+impl From<ErrorCode> for NodeError {
+    fn from(error_code: ErrorCode) -> Self {
+        use ErrorCode::*;
+
+        match error_code {
+            FunctionDisabled => NodeError::FunctionDisabled,
+            RequestThrottled => NodeError::Throttled,
+            FailedQuery => NodeError::QueryFailed,
+            InternalError => NodeError::Internal,
+            WasmPreprocessing => NodeError::WasmPreprocessing,
+            NotFound
+            | RootNotFound
+            | DictionaryURefNotFound
+            | NoCompleteBlocks
+            | SwitchBlockNotFound
+            | SwitchBlockParentNotFound
+            | EmptyBlockchain
+            | PurseNotFound => NodeError::NotFound(error_code),
+            InvalidItemVariant
+            | BadRequest
+            | TransferRecordMalformedKey
+            | MalformedInformationRequest
+            | TooLittleBytesForRequestHeaderVersion
+            | MalformedCommandHeaderVersion
+            | MalformedCommandHeader
+            | MalformedCommand => NodeError::BadRequest(error_code),
+            UnsupportedRequest
+            | UnsupportedRewardsV1Request
+            | CommandHeaderVersionMismatch
+            | ReceivedV1Transaction => NodeError::UnsupportedRequest(error_code),
+            GasPriceToleranceTooLow => NodeError::GasPriceTooHigh(error_code),
+            NoSuchAddressableEntity
+            | NoSuchContractAtHash
+            | NoSuchEntryPoint
+            | NoSuchPackageAtHash
+            | InvalidEntityAtVersion
+            | DisabledEntityAtVersion
+            | MissingEntityAtVersion
+            | InvalidAssociatedKeys
+            | InsufficientSignatureWeight
+            | InsufficientBalance
+            | UnknownBalance => NodeError::TransactionNotExecutable(error_code),
+            InvalidDeployChainName
+            | InvalidDeployDependenciesNoLongerSupported
+            | InvalidDeployExcessiveSize
+            | InvalidDeployExcessiveTimeToLive
+            | InvalidDeployTimestampInFuture
+            | InvalidDeployBodyHash
+            | InvalidDeployHash
+            | InvalidDeployEmptyApprovals
+            | InvalidDeployApproval
+            | InvalidDeployExcessiveSessionArgsLength
+            | InvalidDeployExcessivePaymentArgsLength
+            | InvalidDeployMissingPaymentAmount
+            | InvalidDeployFailedToParsePaymentAmount
+            | InvalidDeployExceededBlockGasLimit
+            | InvalidDeployMissingTransferAmount
+            | InvalidDeployFailedToParseTransferAmount
+            | InvalidDeployInsufficientTransferAmount
+            | InvalidDeployExcessiveApprovals
+            | InvalidDeployUnableToCalculateGasLimit
+            | InvalidDeployUnableToCalculateGasCost
+            | InvalidDeployUnspecified
+            | InvalidDeployGasLimitNotSupported
+            | InvalidDeployInvalidRuntime
+            | InvalidDeployExceededWasmLaneGasLimit
+            | InvalidDeployInvalidPaymentAmount
+            | DeployInvalidPaymentVariant
+            | DeployMissingPaymentAmount
+            | DeployFailedToParsePaymentAmount
+            | DeployMissingTransferTarget
+            | DeployMissingModuleBytes
+            | InvalidTransactionChainName
+            | InvalidTransactionExcessiveSize
+            | InvalidTransactionExcessiveTimeToLive
+            | InvalidTransactionTimestampInFuture
+            | InvalidTransactionBodyHash
+            | InvalidTransactionHash
+            | InvalidTransactionEmptyApprovals
+            | InvalidTransactionInvalidApproval
+            | InvalidTransactionExcessiveArgsLength
+            | InvalidTransactionExcessiveApprovals
+            | InvalidTransactionExceedsBlockGasLimit
+            | InvalidTransactionMissingArg
+            | InvalidTransactionUnexpectedArgType
+            | InvalidTransactionInvalidArg
+            | InvalidTransactionInsufficientTransferAmount
+            | InvalidTransactionEntryPointCannotBeCustom
+            | InvalidTransactionEntryPointMustBeCustom
+            | InvalidTransactionEmptyModuleBytes
+            | InvalidTransactionGasPriceConversion
+            | InvalidTransactionUnableToCalculateGasLimit
+            | InvalidTransactionUnableToCalculateGasCost
+            | InvalidTransactionPricingMode
+            | InvalidTransactionUnspecified
+            | InvalidTransactionOrDeployUnspecified
+            | InvalidTransactionEntryPointCannotBeCall
+            | InvalidTransactionInvalidTransactionLane
+            | InvalidTransactionRuntime
+            | InvalidTransactionNoLaneMatches
+            | InvalidTransactionEntryPointMustBeCall
+            | InvalidTransactionCannotDeserializeField
+            | InvalidTransactionCannotCalculateFieldsHash
+            | InvalidTransactionUnexpectedFields
+            | InvalidTransactionExpectedBytesArguments
+            | InvalidTransactionMissingSeed
+            | InvalidTransactionInsufficientBurnAmount
+            | InvalidTransactionInvalidPaymentAmount
+            | InvalidTransactionUnexpectedEntryPoint
+            | TransactionHasMalformedBinaryRepresentation
+            | ExpectedDeploy
+            | ExpectedTransaction
+            | TransactionExpired
+            | MissingOrIncorrectParameters
+            | ExpectedNamedArguments
+            | PricingModeNotSupported
+            | InsufficientAmountArgValue
+            | InvalidMinimumDelegationAmount
+            | InvalidMaximumDelegationAmount
+            | InvalidReservedSlots
+            | InvalidDelegationAmount
+            | UnsupportedInvocationTarget => NodeError::InvalidTransaction(error_code),
+            // Not an error, hence never returned with a failed response.
+            NoError => NodeError::Unknown(error_code as u16),
+        }
+    }
+}
+
+/// Error whilst loading or validating a proxy configuration.
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to read config file {}: {source}", path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Failed to parse config: {0}")]
+    ParseError(#[from] toml::de::Error),
+
+    #[error("Unknown environment variable {0}")]
+    UnknownEnvVar(String),
+
+    #[error("Invalid value for environment variable {name}: {value:?}")]
+    InvalidEnvVar { name: String, value: String },
+
+    #[error("Invalid config: {field} {reason}")]
+    InvalidValue {
+        field: &'static str,
+        reason: &'static str,
+    },
+}
+
+/// Error whilst bootstrapping or syncing a light client.
+#[derive(Error, Debug)]
+pub enum LightClientError {
+    #[error(transparent)]
+    Binary(#[from] BinaryError),
+
+    #[error("Verification failed: {0}")]
+    Verification(#[from] ChainError),
+
+    #[error("Block {0:?} not found")]
+    BlockNotFound(Option<BlockIdentifier>),
+
+    #[error("Finality signatures of block {0} not found")]
+    SignaturesNotFound(BlockHash),
+
+    #[error("Validators of the era of trusted block {0} cannot be determined")]
+    NoTrustedValidators(BlockHash),
+
+    #[error("Switch block of era {0} not found")]
+    SwitchBlockNotFound(EraId),
+}
@@ -0,0 +1,292 @@
+use casper_types::{
+    BlockHash, BlockHeader, BlockIdentifier, Digest, EraId, Key, PublicKey, StoredValue, U512,
+};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
+
+use super::{client::Client, error::LightClientError};
+use crate::crypto::{
+    chain::verify_block_hash, finality::verify_finality_signatures, ChainError, FinalityThreshold,
+};
+
+/// Light client configuration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LightClientConfig {
+    /// Fraction of an era's validator weight whose signatures finalize a block.
+    pub finality_threshold: FinalityThreshold,
+}
+
+/// Latest finalized header, together with the validators of the era of the blocks following it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrustedState {
+    pub header: BlockHeader,
+    pub era_id: EraId,
+    pub validator_weights: BTreeMap<PublicKey, U512>,
+}
+
+impl TrustedState {
+    /// Advances to a finalized header following the current header.
+    fn advance(self, header: BlockHeader) -> Result<Self, ChainError> {
+        let validator_weights = if header.is_switch_block() {
+            header.next_era_validator_weights().cloned().ok_or(
+                ChainError::MissingValidatorWeights {
+                    height: header.height(),
+                },
+            )?
+        } else {
+            self.validator_weights
+        };
+
+        Ok(TrustedState {
+            era_id: header.next_block_era_id(),
+            header,
+            validator_weights,
+        })
+    }
+}
+
+/// Client trusting a single block hash, and blocks finalized by validators known to it.
+///
+/// Starting from the trusted block, syncing walks forward era by era: the switch block ending each
+/// era is located by height, and trusted once signed by that era's validators, whose weights
+/// it carries for the next era.  Within the node's current era the node's latest block signed by
+/// the era's validators is trusted likewise, becoming the latest finalized header against which
+/// state may be queried.
+pub struct LightClient {
+    client: Arc<Client>,
+    config: LightClientConfig,
+    state: RwLock<TrustedState>,
+}
+
+impl LightClient {
+    /// Bootstraps from a trusted block hash.
+    ///
+    /// The trusted block must be a switch block, or a block referring to the switch block
+    /// preceding its era, from which the validators of its era are determined.
+    pub async fn new(
+        client: Arc<Client>,
+        trusted_hash: BlockHash,
+        config: LightClientConfig,
+    ) -> Result<Self, LightClientError> {
+        let header = header_by_hash(&client, trusted_hash).await?;
+        let validator_weights = match (header.next_era_validator_weights(), &header) {
+            (Some(weights), _) => weights.clone(),
+            (None, BlockHeader::V2(header_v2)) => {
+                let switch_block_hash = header_v2
+                    .last_switch_block_hash()
+                    .ok_or(LightClientError::NoTrustedValidators(trusted_hash))?;
+                let switch_block = header_by_hash(&client, switch_block_hash).await?;
+                match switch_block.next_era_validator_weights() {
+                    Some(weights) if switch_block.next_block_era_id() == header.era_id() => {
+                        weights.clone()
+                    }
+                    _ => return Err(LightClientError::NoTrustedValidators(trusted_hash)),
+                }
+            }
+            (None, BlockHeader::V1(_)) => {
+                return Err(LightClientError::NoTrustedValidators(trusted_hash))
+            }
+        };
+        let state = TrustedState {
+            era_id: header.next_block_era_id(),
+            header,
+            validator_weights,
+        };
+
+        Ok(LightClient {
+            client,
+            config,
+            state: RwLock::new(state),
+        })
+    }
+
+    pub fn config(&self) -> &LightClientConfig {
+        &self.config
+    }
+
+    /// Returns the latest finalized header along with the validators known to the client.
+    pub fn trusted_state(&self) -> TrustedState {
+        self.state
+            .read()
+            .expect("light client lock poisoned")
+            .clone()
+    }
+
+    /// Returns the latest finalized header.
+    pub fn latest_finalized_header(&self) -> BlockHeader {
+        self.trusted_state().header
+    }
+
+    /// Returns the state root hash of the latest finalized header.
+    pub fn state_root_hash(&self) -> Digest {
+        *self.latest_finalized_header().state_root_hash()
+    }
+
+    /// Advances the latest finalized header to the node's latest finalized block, returning it.
+    ///
+    /// Progress is kept era by era, even if a later era fails verification.  Within the node's
+    /// current era, blocks not yet signed by enough of the era's validators are passed over in
+    /// favour of the latest block preceding them which is.
+    pub async fn sync(&self) -> Result<BlockHeader, LightClientError> {
+        let mut state = self.trusted_state();
+        loop {
+            let latest = self
+                .client
+                .get()
+                .information()
+                .get_block_header(None)
+                .await?
+                .ok_or(LightClientError::BlockNotFound(None))?;
+            if latest.height() <= state.header.height() {
+                return Ok(state.header);
+            }
+            if latest.era_id() == state.era_id {
+                return match self.latest_finalized_in_era(&state, latest).await? {
+                    Some(header) => {
+                        state = state.advance(header)?;
+                        self.trust(&state);
+                        Ok(state.header)
+                    }
+                    None => Ok(state.header),
+                };
+            }
+            if latest.era_id() < state.era_id {
+                return Err(ChainError::EraMismatch {
+                    height: latest.height(),
+                    expected: state.era_id,
+                    got: latest.era_id(),
+                }
+                .into());
+            }
+            let header = self.find_switch_block(&state, latest.height()).await?;
+            self.verify_finality(&header, &state).await?;
+            state = state.advance(header)?;
+            self.trust(&state);
+        }
+    }
+
+    /// Returns value stored under a key, following a path of named keys, as of the latest
+    /// finalized header, verifying the proof returned by the node.
+    pub async fn get_verified_item(
+        &self,
+        base_key: Key,
+        path: Vec<String>,
+    ) -> Result<Option<StoredValue>, LightClientError> {
+        Ok(self
+            .client
+            .get()
+            .state()
+            .get_verified_item(self.state_root_hash(), base_key, path)
+            .await?)
+    }
+
+    /// Returns the latest finalized block of the current era, stepping back from the given block
+    /// of that era until one is signed by enough of the era's validators, or `None` if none
+    /// follows the current header.
+    async fn latest_finalized_in_era(
+        &self,
+        state: &TrustedState,
+        mut header: BlockHeader,
+    ) -> Result<Option<BlockHeader>, LightClientError> {
+        loop {
+            match self.verify_finality(&header, state).await {
+                Ok(()) => return Ok(Some(header)),
+                Err(LightClientError::SignaturesNotFound(_))
+                | Err(LightClientError::Verification(ChainError::InsufficientSignatureWeight {
+                    ..
+                })) => {}
+                Err(error) => return Err(error),
+            }
+            let height = header.height() - 1;
+            if height <= state.header.height() {
+                return Ok(None);
+            }
+            header = self.header_at(height).await?;
+        }
+    }
+
+    /// Stores a state advanced from the trusted state, unless a concurrent sync went further.
+    fn trust(&self, state: &TrustedState) {
+        let mut trusted = self.state.write().expect("light client lock poisoned");
+        if trusted.header.height() < state.header.height() {
+            *trusted = state.clone();
+        }
+    }
+
+    /// Locates the switch block ending the current era, given the height of a later block.
+    async fn find_switch_block(
+        &self,
+        state: &TrustedState,
+        later_height: u64,
+    ) -> Result<BlockHeader, LightClientError> {
+        // Blocks up to `low` are within the era, blocks from `high` are not.
+        let (mut low, mut high) = (state.header.height(), later_height);
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            if self.header_at(middle).await?.era_id() <= state.era_id {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        let header = self.header_at(low).await?;
+        if header.era_id() != state.era_id || !header.is_switch_block() {
+            return Err(LightClientError::SwitchBlockNotFound(state.era_id));
+        }
+        Ok(header)
+    }
+
+    async fn header_at(&self, height: u64) -> Result<BlockHeader, LightClientError> {
+        let block_id = BlockIdentifier::Height(height);
+        self.client
+            .get()
+            .information()
+            .get_block_header(Some(block_id))
+            .await?
+            .ok_or(LightClientError::BlockNotFound(Some(block_id)))
+    }
+
+    /// Verifies a header of the current era is signed by enough of the era's validators.
+    async fn verify_finality(
+        &self,
+        header: &BlockHeader,
+        state: &TrustedState,
+    ) -> Result<(), LightClientError> {
+        if header.era_id() != state.era_id {
+            return Err(ChainError::UnknownValidators(header.era_id()).into());
+        }
+        let block_hash = header.block_hash();
+        let signatures = self
+            .client
+            .get()
+            .record()
+            .get_finality_signatures(BlockIdentifier::Hash(block_hash))
+            .await?
+            .ok_or(LightClientError::SignaturesNotFound(block_hash))?;
+        verify_finality_signatures(
+            header,
+            &signatures,
+            &state.validator_weights,
+            self.config.finality_threshold,
+        )?;
+        Ok(())
+    }
+}
+
+/// Fetches a header by hash, verifying it hashes as identified.
+async fn header_by_hash(
+    client: &Client,
+    block_hash: BlockHash,
+) -> Result<BlockHeader, LightClientError> {
+    let block_id = BlockIdentifier::Hash(block_hash);
+    let header = client
+        .get()
+        .record()
+        .get_block_header(block_id)
+        .await?
+        .ok_or(LightClientError::BlockNotFound(Some(block_id)))?;
+    verify_block_hash(&header, &block_hash)?;
+    Ok(header)
+}
@@ -1 +1,11 @@
-use proxy::proxy::Proxy;
+pub mod client;
+pub mod error;
+pub mod light_client;
+pub mod proxy;
+#[cfg(feature = "testing")]
+pub mod server;
+pub mod types;
+
+pub use client::Client;
+pub use error::BinaryError;
+pub use light_client::LightClient;
@@ -0,0 +1,58 @@
+use crate::api::node::binary::error::{BinaryError, ProtocolError};
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use l1_binary_port::{BinaryMessage, BinaryResponseAndRequest, Command, CommandHeader, GetRequest};
+
+/// Encodes a request, prefixed with a header carrying the request's id, as a binary message.
+pub fn encode_request(request: &Command, id: u16) -> Result<BinaryMessage, BinaryError> {
+    let header = CommandHeader::new(request.tag(), id);
+    let mut bytes = Vec::with_capacity(header.serialized_length() + request.serialized_length());
+    header.write_bytes(&mut bytes)?;
+    request.write_bytes(&mut bytes)?;
+
+    Ok(BinaryMessage::new(bytes))
+}
+
+/// Decodes a binary message as a response, verifying that it echoes the request with given id.
+pub fn decode_response(
+    message: &BinaryMessage,
+    expected_id: u16,
+) -> Result<BinaryResponseAndRequest, BinaryError> {
+    let (got, response) = decode_response_and_id(message)?;
+    if got != expected_id {
+        return Err(ProtocolError::RequestIdMismatch {
+            expected: expected_id,
+            got,
+        }
+        .into());
+    }
+
+    Ok(response)
+}
+
+/// Decodes a binary message as a response, along with the id of the request it echoes.
+pub fn decode_response_and_id(
+    message: &BinaryMessage,
+) -> Result<(u16, BinaryResponseAndRequest), BinaryError> {
+    let response: BinaryResponseAndRequest = bytesrepr::deserialize_from_slice(message.payload())?;
+    let id = read_request_id(response.request())?;
+
+    Ok((id, response))
+}
+
+/// Reads id of request from its encoded header.
+pub fn read_request_id(request: &[u8]) -> Result<u16, BinaryError> {
+    let (header, _) = CommandHeader::from_bytes(request)?;
+    Ok(header.id())
+}
+
+/// Returns the kind of a request, e.g. `get_record`, as labelled within traces.
+pub fn request_kind(request: &Command) -> &'static str {
+    match request {
+        Command::Get(GetRequest::Record { .. }) => "get_record",
+        Command::Get(GetRequest::Information { .. }) => "get_information",
+        Command::Get(GetRequest::State(_)) => "get_state",
+        Command::Get(GetRequest::Trie { .. }) => "get_trie",
+        Command::TryAcceptTransaction { .. } => "try_accept_transaction",
+        Command::TrySpeculativeExec { .. } => "try_speculative_exec",
+    }
+}
@@ -1,9 +1,36 @@
-use std::net::SocketAddr;
+use datasize::DataSize;
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer,
+};
+use std::{
+    ffi::OsString,
+    fmt::{self, Formatter},
+    fs,
+    net::SocketAddr,
+    path::Path,
+    str::FromStr,
+};
+
+use super::constants::{
+    DEFAULT_CLIENT_ACCESS_TIMEOUT_SECS, DEFAULT_EXPONENTIAL_BACKOFF_BASE_MS,
+    DEFAULT_EXPONENTIAL_BACKOFF_COEFFICIENT, DEFAULT_EXPONENTIAL_BACKOFF_MAX_ATTEMPTS,
+    DEFAULT_EXPONENTIAL_BACKOFF_MAX_MS, DEFAULT_MAX_PAYLOAD_SIZE, DEFAULT_MESSAGE_TIMEOUT_SECS,
+    DEFAULT_NODE_CONNECT_ADDRESS, DEFAULT_NODE_REQUEST_LIMIT, DEFAULT_POOL_EJECTION_PERIOD_SECS,
+    DEFAULT_POOL_EJECTION_THRESHOLD, DEFAULT_POOL_HEALTH_CHECK_INTERVAL_SECS,
+    DEFAULT_POOL_MAX_RETRIES, DEFAULT_REQUEST_BUFFER_SIZE,
+};
+use crate::api::node::binary::error::ConfigError;
+
+/// Prefix of environment variables overriding proxy configuration.
+pub const ENV_PREFIX: &str = "CASPER_BINARY_PORT_";
 
 /// Proxy configuration.
+///
+/// Fields absent from a config file take their default values.
 #[derive(Clone, DataSize, Debug, Deserialize, PartialEq, Eq)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
-#[serde(deny_unknown_fields)]
+#[serde(default, deny_unknown_fields)]
 pub struct ProxyConfig {
     /// Address of the node.
     pub address: SocketAddr,
@@ -22,10 +49,175 @@ pub struct ProxyConfig {
     pub exponential_backoff: ExponentialBackoffConfig,
 }
 
+impl Default for ProxyConfig {
+    fn default() -> Self {
+        ProxyConfig {
+            address: SocketAddr::from(DEFAULT_NODE_CONNECT_ADDRESS),
+            max_message_size_bytes: DEFAULT_MAX_PAYLOAD_SIZE,
+            message_timeout_secs: DEFAULT_MESSAGE_TIMEOUT_SECS,
+            client_access_timeout_secs: DEFAULT_CLIENT_ACCESS_TIMEOUT_SECS,
+            request_limit: DEFAULT_NODE_REQUEST_LIMIT,
+            request_buffer_size: DEFAULT_REQUEST_BUFFER_SIZE,
+            exponential_backoff: ExponentialBackoffConfig::default(),
+        }
+    }
+}
+
+impl ProxyConfig {
+    /// Loads configuration by layering, in increasing precedence, defaults, an optional TOML file
+    /// and environment variables, then validates it.
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let config = match path {
+            Some(path) => ProxyConfig::from_toml_file(path)?,
+            None => ProxyConfig::default(),
+        };
+        let config = config.with_env_overrides(env_vars(std::env::vars_os())?)?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Parses a TOML document, layered over defaults.
+    pub fn from_toml_str(toml: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Parses a TOML file, layered over defaults.
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let toml = fs::read_to_string(path).map_err(|source| ConfigError::ReadError {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        ProxyConfig::from_toml_str(&toml)
+    }
+
+    /// Overrides fields with those environment variables which are prefixed with `ENV_PREFIX`
+    /// and named after the upper-cased field, e.g. `CASPER_BINARY_PORT_REQUEST_LIMIT` or
+    /// `CASPER_BINARY_PORT_EXPONENTIAL_BACKOFF_MAX_ATTEMPTS`.  Other variables are ignored.
+    pub fn with_env_overrides(
+        mut self,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self, ConfigError> {
+        for (name, value) in vars {
+            let Some(field) = name.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            let backoff = &mut self.exponential_backoff;
+            match field {
+                "ADDRESS" => self.address = parse_env_var(&name, &value)?,
+                "MAX_MESSAGE_SIZE_BYTES" => {
+                    self.max_message_size_bytes = parse_env_var(&name, &value)?
+                }
+                "MESSAGE_TIMEOUT_SECS" => self.message_timeout_secs = parse_env_var(&name, &value)?,
+                "CLIENT_ACCESS_TIMEOUT_SECS" => {
+                    self.client_access_timeout_secs = parse_env_var(&name, &value)?
+                }
+                "REQUEST_LIMIT" => self.request_limit = parse_env_var(&name, &value)?,
+                "REQUEST_BUFFER_SIZE" => self.request_buffer_size = parse_env_var(&name, &value)?,
+                "EXPONENTIAL_BACKOFF_INITIAL_DELAY_MS" => {
+                    backoff.initial_delay_ms = parse_env_var(&name, &value)?
+                }
+                "EXPONENTIAL_BACKOFF_MAX_DELAY_MS" => {
+                    backoff.max_delay_ms = parse_env_var(&name, &value)?
+                }
+                "EXPONENTIAL_BACKOFF_COEFFICIENT" => {
+                    backoff.coefficient = parse_env_var(&name, &value)?
+                }
+                "EXPONENTIAL_BACKOFF_MAX_ATTEMPTS" => {
+                    backoff.max_attempts = parse_env_var(&name, &value)?
+                }
+                _ => return Err(ConfigError::UnknownEnvVar(name)),
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Checks that values allow the proxy to make progress.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |field, reason| Err(ConfigError::InvalidValue { field, reason });
+        if self.max_message_size_bytes == 0 {
+            return invalid("max_message_size_bytes", "must be greater than zero");
+        }
+        if self.message_timeout_secs == 0 {
+            return invalid("message_timeout_secs", "must be greater than zero");
+        }
+        if self.client_access_timeout_secs == 0 {
+            return invalid("client_access_timeout_secs", "must be greater than zero");
+        }
+        if self.request_limit == 0 {
+            return invalid("request_limit", "must be greater than zero");
+        }
+        if self.request_buffer_size == 0 {
+            return invalid("request_buffer_size", "must be greater than zero");
+        }
+        let backoff = &self.exponential_backoff;
+        if backoff.coefficient == 0 {
+            return invalid(
+                "exponential_backoff.coefficient",
+                "must be greater than zero",
+            );
+        }
+        if backoff.initial_delay_ms > backoff.max_delay_ms {
+            return invalid(
+                "exponential_backoff.initial_delay_ms",
+                "must not exceed exponential_backoff.max_delay_ms",
+            );
+        }
+        if backoff.max_attempts == MaxAttempts::Finite(0) {
+            return invalid(
+                "exponential_backoff.max_attempts",
+                "must allow at least one attempt",
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns environment variables which are valid unicode, rejecting any others prefixed with
+/// `ENV_PREFIX`.
+fn env_vars(
+    vars: impl IntoIterator<Item = (OsString, OsString)>,
+) -> Result<Vec<(String, String)>, ConfigError> {
+    let mut env_vars = vec![];
+    for (name, value) in vars {
+        match (name.into_string(), value.into_string()) {
+            (Ok(name), Ok(value)) => env_vars.push((name, value)),
+            (Ok(name), Err(value)) if name.starts_with(ENV_PREFIX) => {
+                return Err(ConfigError::InvalidEnvVar {
+                    name,
+                    value: value.to_string_lossy().into_owned(),
+                })
+            }
+            (Err(name), _) if name.to_string_lossy().starts_with(ENV_PREFIX) => {
+                return Err(ConfigError::UnknownEnvVar(
+                    name.to_string_lossy().into_owned(),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(env_vars)
+}
+
+fn parse_env_var<T: FromStr>(name: &str, value: &str) -> Result<T, ConfigError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| ConfigError::InvalidEnvVar {
+            name: name.to_string(),
+            value: value.to_string(),
+        })
+}
+
 /// Exponential backoff configuration for re-connects.
 #[derive(Clone, DataSize, Debug, Deserialize, PartialEq, Eq)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
-#[serde(deny_unknown_fields)]
+#[serde(default, deny_unknown_fields)]
 pub struct ExponentialBackoffConfig {
     /// Initial wait time before the first re-connect attempt.
     pub initial_delay_ms: u64,
@@ -33,11 +225,22 @@ pub struct ExponentialBackoffConfig {
     pub max_delay_ms: u64,
     /// The multiplier to apply to the previous delay to get the next delay.
     pub coefficient: u64,
-    /// Maximum number of connection attempts.
+    /// Maximum number of connection attempts, either `"infinite"` or a number.
     pub max_attempts: MaxAttempts,
 }
 
-#[derive(Clone, DataSize, Debug, Deserialize, PartialEq, Eq)]
+impl Default for ExponentialBackoffConfig {
+    fn default() -> Self {
+        ExponentialBackoffConfig {
+            initial_delay_ms: DEFAULT_EXPONENTIAL_BACKOFF_BASE_MS,
+            max_delay_ms: DEFAULT_EXPONENTIAL_BACKOFF_MAX_MS,
+            coefficient: DEFAULT_EXPONENTIAL_BACKOFF_COEFFICIENT,
+            max_attempts: DEFAULT_EXPONENTIAL_BACKOFF_MAX_ATTEMPTS,
+        }
+    }
+}
+
+#[derive(Clone, DataSize, Debug, PartialEq, Eq)]
 pub enum MaxAttempts {
     /// Retry to the end of time.
     Infinite,
@@ -54,3 +257,113 @@ impl MaxAttempts {
         }
     }
 }
+
+/// Parses either `infinite` or a number of attempts.
+impl FromStr for MaxAttempts {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.eq_ignore_ascii_case("infinite") {
+            return Ok(MaxAttempts::Infinite);
+        }
+        value.parse().map(MaxAttempts::Finite)
+    }
+}
+
+/// Deserializes either `"infinite"` or a number of attempts, as spelled within environment
+/// variables.
+impl<'de> Deserialize<'de> for MaxAttempts {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(MaxAttemptsVisitor)
+    }
+}
+
+struct MaxAttemptsVisitor;
+
+impl Visitor<'_> for MaxAttemptsVisitor {
+    type Value = MaxAttempts;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("\"infinite\" or a number of attempts")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<MaxAttempts, E> {
+        value
+            .trim()
+            .parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<MaxAttempts, E> {
+        usize::try_from(value)
+            .map(MaxAttempts::Finite)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<MaxAttempts, E> {
+        usize::try_from(value)
+            .map(MaxAttempts::Finite)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+}
+
+/// Configuration of a pool of proxies, one per node.
+#[derive(Clone, DataSize, Debug, Deserialize, PartialEq, Eq)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(default, deny_unknown_fields)]
+pub struct PoolConfig {
+    /// Addresses of the nodes, each otherwise configured as per the shared proxy configuration.
+    pub addresses: Vec<SocketAddr>,
+    /// Strategy selecting the node to which a request is dispatched.
+    pub selection: SelectionStrategy,
+    /// Number of consecutive failures after which a node is ejected from selection.
+    pub ejection_threshold: u32,
+    /// Period in seconds for which an ejected node is excluded from selection.
+    pub ejection_period_secs: u64,
+    /// Interval in seconds between health checks of each node, zero disables health checks.
+    pub health_check_interval_secs: u64,
+    /// Number of times a failed get request is retried, each on a node not yet tried.
+    pub max_retries: usize,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        PoolConfig {
+            addresses: vec![],
+            selection: SelectionStrategy::default(),
+            ejection_threshold: DEFAULT_POOL_EJECTION_THRESHOLD,
+            ejection_period_secs: DEFAULT_POOL_EJECTION_PERIOD_SECS,
+            health_check_interval_secs: DEFAULT_POOL_HEALTH_CHECK_INTERVAL_SECS,
+            max_retries: DEFAULT_POOL_MAX_RETRIES,
+        }
+    }
+}
+
+impl PoolConfig {
+    /// Checks that values allow the pool to make progress.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.addresses.is_empty() {
+            return Err(ConfigError::InvalidValue {
+                field: "addresses",
+                reason: "must not be empty",
+            });
+        }
+        if self.ejection_threshold == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "ejection_threshold",
+                reason: "must be greater than zero",
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, DataSize, Debug, Default, Deserialize, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Nodes take turns.
+    #[default]
+    RoundRobin,
+    /// Node with the fewest requests awaiting a response.
+    LeastInFlight,
+}
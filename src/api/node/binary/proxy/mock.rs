@@ -0,0 +1,313 @@
+use async_trait::async_trait;
+use casper_types::bytesrepr::{self, Bytes, FromBytes, ToBytes};
+use l1_binary_port::{
+    BinaryResponse, BinaryResponseAndRequest, Command, CommandHeader, CommandTag, ErrorCode,
+    GetRequest, InformationRequestTag, PayloadEntity, RecordId,
+};
+use std::{
+    fmt, fs, io,
+    path::Path,
+    sync::{
+        atomic::{AtomicU16, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use super::{codec::encode_request, proxy::Proxy};
+use crate::api::node::binary::error::BinaryError;
+
+/// Selects the requests to which a mock proxy rule applies.
+pub enum RequestMatcher {
+    /// Matches every request.
+    Any,
+    /// Matches requests of a command type.
+    Command(CommandTag),
+    /// Matches record requests of a record type, whatever the key.
+    Record(RecordId),
+    /// Matches information requests of an information type, whatever the key.
+    Information(InformationRequestTag),
+    /// Matches global state requests.
+    State,
+    /// Matches trie requests.
+    Trie,
+    /// Matches a request exactly.
+    Request(Box<Command>),
+    /// Matches requests satisfying a predicate.
+    Custom(Box<dyn Fn(&Command) -> bool + Send + Sync>),
+}
+
+impl RequestMatcher {
+    pub fn custom(predicate: impl Fn(&Command) -> bool + Send + Sync + 'static) -> Self {
+        RequestMatcher::Custom(Box::new(predicate))
+    }
+
+    pub fn matches(&self, request: &Command) -> bool {
+        match (self, request) {
+            (RequestMatcher::Any, _) => true,
+            (RequestMatcher::Command(tag), request) => request.tag() == *tag,
+            (
+                RequestMatcher::Record(record_id),
+                Command::Get(GetRequest::Record {
+                    record_type_tag, ..
+                }),
+            ) => *record_type_tag == u16::from(*record_id),
+            (
+                RequestMatcher::Information(tag),
+                Command::Get(GetRequest::Information { info_type_tag, .. }),
+            ) => *info_type_tag == u16::from(*tag),
+            (RequestMatcher::State, Command::Get(GetRequest::State(_))) => true,
+            (RequestMatcher::Trie, Command::Get(GetRequest::Trie { .. })) => true,
+            (RequestMatcher::Request(expected), request) => **expected == *request,
+            (RequestMatcher::Custom(predicate), request) => predicate(request),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for RequestMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestMatcher::Any => write!(f, "Any"),
+            RequestMatcher::Command(tag) => f.debug_tuple("Command").field(tag).finish(),
+            RequestMatcher::Record(record_id) => f.debug_tuple("Record").field(record_id).finish(),
+            RequestMatcher::Information(tag) => f.debug_tuple("Information").field(tag).finish(),
+            RequestMatcher::State => write!(f, "State"),
+            RequestMatcher::Trie => write!(f, "Trie"),
+            RequestMatcher::Request(request) => f.debug_tuple("Request").field(request).finish(),
+            RequestMatcher::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl From<Command> for RequestMatcher {
+    fn from(request: Command) -> Self {
+        RequestMatcher::Request(Box::new(request))
+    }
+}
+
+impl From<GetRequest> for RequestMatcher {
+    fn from(request: GetRequest) -> Self {
+        RequestMatcher::Request(Box::new(Command::Get(request)))
+    }
+}
+
+impl From<CommandTag> for RequestMatcher {
+    fn from(tag: CommandTag) -> Self {
+        RequestMatcher::Command(tag)
+    }
+}
+
+impl From<RecordId> for RequestMatcher {
+    fn from(record_id: RecordId) -> Self {
+        RequestMatcher::Record(record_id)
+    }
+}
+
+impl From<InformationRequestTag> for RequestMatcher {
+    fn from(tag: InformationRequestTag) -> Self {
+        RequestMatcher::Information(tag)
+    }
+}
+
+/// Outcome of a request matched by a mock proxy rule.
+enum MockOutcome {
+    /// Serialized response, deserialized afresh upon each request.
+    Response(Vec<u8>),
+    Error(Box<dyn Fn() -> BinaryError + Send + Sync>),
+}
+
+struct MockRule {
+    matcher: RequestMatcher,
+    outcome: MockOutcome,
+}
+
+/// Proxy that never reaches a node - requests are answered by programmable rules.
+///
+/// The first registered rule matching a request determines its outcome, requests matching no
+/// rule are answered with an empty response.  Received requests are recorded for inspection.
+#[derive(Default)]
+pub struct MockProxy {
+    rules: Vec<MockRule>,
+    latencies: Vec<(RequestMatcher, Duration)>,
+    requests: Mutex<Vec<Command>>,
+    next_request_id: AtomicU16,
+}
+
+impl MockProxy {
+    pub fn new() -> Self {
+        MockProxy::default()
+    }
+
+    /// Answers matching requests with a response.
+    pub fn with_response(
+        mut self,
+        matcher: impl Into<RequestMatcher>,
+        response: BinaryResponse,
+    ) -> Self {
+        let response = response.to_bytes().expect("mock response should serialize");
+        self.rules.push(MockRule {
+            matcher: matcher.into(),
+            outcome: MockOutcome::Response(response),
+        });
+        self
+    }
+
+    /// Answers matching requests with a successful response carrying a value.
+    pub fn with_value<T>(self, matcher: impl Into<RequestMatcher>, value: T) -> Self
+    where
+        T: PayloadEntity + ToBytes,
+    {
+        self.with_response(matcher, BinaryResponse::from_value(value))
+    }
+
+    /// Answers matching requests with a failed response carrying an error code.
+    pub fn with_error_code(self, matcher: impl Into<RequestMatcher>, error: ErrorCode) -> Self {
+        self.with_response(matcher, BinaryResponse::new_error(error))
+    }
+
+    /// Fails matching requests without a response, e.g. to simulate transport failures.
+    pub fn with_error(
+        mut self,
+        matcher: impl Into<RequestMatcher>,
+        error: impl Fn() -> BinaryError + Send + Sync + 'static,
+    ) -> Self {
+        self.rules.push(MockRule {
+            matcher: matcher.into(),
+            outcome: MockOutcome::Error(Box::new(error)),
+        });
+        self
+    }
+
+    /// Delays the outcome of matching requests.  The first registered latency that matches
+    /// applies.
+    pub fn with_latency(mut self, matcher: impl Into<RequestMatcher>, latency: Duration) -> Self {
+        self.latencies.push((matcher.into(), latency));
+        self
+    }
+
+    /// Answers the request recorded within a fixture file with the response recorded therein.
+    ///
+    /// A fixture file holds a serialized `BinaryResponseAndRequest`, i.e. the payload of a
+    /// message returned by a node, as written by `write_fixture`.
+    pub fn with_fixture(self, path: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        let fixture: BinaryResponseAndRequest =
+            bytesrepr::deserialize_from_slice(bytes).map_err(invalid_fixture)?;
+        let request = decode_request(fixture.request()).map_err(invalid_fixture)?;
+
+        Ok(self.with_response(request, fixture.into()))
+    }
+
+    /// Loads every fixture file, i.e. with a `.bin` extension, within a directory in name order.
+    pub fn with_fixtures(mut self, dir: impl AsRef<Path>) -> io::Result<Self> {
+        let mut paths = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        paths.retain(|path| path.extension().is_some_and(|extension| extension == "bin"));
+        paths.sort();
+        for path in paths {
+            self = self.with_fixture(path)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<Command> {
+        self.requests
+            .lock()
+            .expect("mock proxy lock poisoned")
+            .iter()
+            .map(clone_request)
+            .collect()
+    }
+
+    /// Returns the number of requests received so far.
+    pub fn request_count(&self) -> usize {
+        self.requests
+            .lock()
+            .expect("mock proxy lock poisoned")
+            .len()
+    }
+
+    /// Forgets the requests received so far.
+    pub fn clear_requests(&self) {
+        self.requests
+            .lock()
+            .expect("mock proxy lock poisoned")
+            .clear();
+    }
+}
+
+impl fmt::Debug for MockProxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockProxy")
+            .field("rules", &self.rules.len())
+            .field("requests", &self.request_count())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Proxy for MockProxy {
+    async fn dispatch(&self, req: Command) -> Result<BinaryResponseAndRequest, BinaryError> {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let request = encode_request(&req, request_id)?;
+        let latency = self
+            .latencies
+            .iter()
+            .find(|(matcher, _)| matcher.matches(&req))
+            .map(|(_, latency)| *latency);
+        let outcome = self
+            .rules
+            .iter()
+            .find(|rule| rule.matcher.matches(&req))
+            .map(|rule| &rule.outcome);
+        self.requests
+            .lock()
+            .expect("mock proxy lock poisoned")
+            .push(req);
+
+        if let Some(latency) = latency {
+            tokio::time::sleep(latency).await;
+        }
+        let response = match outcome {
+            Some(MockOutcome::Response(response)) => bytesrepr::deserialize_from_slice(response)?,
+            Some(MockOutcome::Error(error)) => return Err(error()),
+            None => BinaryResponse::new_empty(),
+        };
+
+        Ok(BinaryResponseAndRequest::new(
+            response,
+            Bytes::from(request.payload().to_vec()),
+        ))
+    }
+}
+
+/// Writes a response, along with the request it answers, as a fixture file.
+pub fn write_fixture(
+    path: impl AsRef<Path>,
+    response: &BinaryResponseAndRequest,
+) -> io::Result<()> {
+    fs::write(path, response.to_bytes().map_err(invalid_fixture)?)
+}
+
+/// Decodes a request, prefixed with its header, as encoded by a client.
+fn decode_request(bytes: &[u8]) -> Result<Command, bytesrepr::Error> {
+    let (header, remainder) = CommandHeader::from_bytes(bytes)?;
+    let tag = CommandTag::try_from(header.type_tag()).map_err(|_| bytesrepr::Error::Formatting)?;
+    Command::try_from((tag, remainder))
+}
+
+fn clone_request(request: &Command) -> Command {
+    let bytes = request
+        .to_bytes()
+        .expect("recorded request should serialize");
+    Command::try_from((request.tag(), bytes.as_slice()))
+        .expect("recorded request should deserialize")
+}
+
+fn invalid_fixture(err: bytesrepr::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
@@ -1,3 +1,16 @@
-use config::ProxyConfig;
-use constants;
-use proxy::Proxy;
+pub mod codec;
+pub mod config;
+pub(crate) mod constants;
+pub mod mock;
+pub mod pool;
+#[allow(clippy::module_inception)]
+pub mod proxy;
+pub mod remote;
+
+pub use config::{
+    ExponentialBackoffConfig, MaxAttempts, PoolConfig, ProxyConfig, SelectionStrategy,
+};
+pub use mock::{MockProxy, RequestMatcher};
+pub use pool::{PoolMemberStatus, PooledProxy};
+pub use proxy::Proxy;
+pub use remote::RemoteProxy;
@@ -0,0 +1,281 @@
+use async_trait::async_trait;
+use l1_binary_port::{BinaryResponseAndRequest, Command, GetRequest, InformationRequest};
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU32, AtomicUsize, Ordering},
+        Arc, Mutex, Weak,
+    },
+    time::Duration,
+};
+use tokio::time::{self, Instant};
+
+#[cfg(feature = "tracing")]
+use super::codec::request_kind;
+use super::{
+    config::{PoolConfig, ProxyConfig, SelectionStrategy},
+    proxy::Proxy,
+    remote::RemoteProxy,
+};
+use crate::{
+    api::node::binary::error::{BinaryError, ConfigError},
+    utils::trace::trace_event,
+};
+
+/// A node within a pool, along with its health.
+struct PoolMember {
+    address: SocketAddr,
+    proxy: Arc<dyn Proxy>,
+    in_flight: AtomicUsize,
+    consecutive_failures: AtomicU32,
+    ejected_until: Mutex<Option<Instant>>,
+}
+
+impl PoolMember {
+    fn is_ejected(&self, now: Instant) -> bool {
+        self.ejected_until
+            .lock()
+            .expect("pool member lock poisoned")
+            .is_some_and(|until| now < until)
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+        *self
+            .ejected_until
+            .lock()
+            .expect("pool member lock poisoned") = None;
+    }
+
+    fn record_failure(&self, config: &PoolConfig) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if failures >= config.ejection_threshold {
+            trace_event!(warn, endpoint = %self.address, failures, "ejecting node from pool");
+            let until = Instant::now() + Duration::from_secs(config.ejection_period_secs);
+            *self
+                .ejected_until
+                .lock()
+                .expect("pool member lock poisoned") = Some(until);
+        }
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "binary_pool_member", skip_all, fields(endpoint = %self.address))
+    )]
+    async fn dispatch(
+        &self,
+        req: Command,
+        config: &PoolConfig,
+    ) -> Result<BinaryResponseAndRequest, BinaryError> {
+        let result = {
+            let _in_flight = InFlightGuard::new(&self.in_flight);
+            self.proxy.dispatch(req).await
+        };
+        match &result {
+            // The node answered, even if declining the request.
+            Ok(_) | Err(BinaryError::Node(_)) => self.record_success(),
+            Err(_) => self.record_failure(config),
+        }
+        result
+    }
+}
+
+/// Counts a request as in flight until dropped, i.e. also when the caller gives up.
+struct InFlightGuard<'a>(&'a AtomicUsize);
+
+impl<'a> InFlightGuard<'a> {
+    fn new(in_flight: &'a AtomicUsize) -> Self {
+        in_flight.fetch_add(1, Ordering::Relaxed);
+        InFlightGuard(in_flight)
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Health of a node within a pool.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolMemberStatus {
+    pub address: SocketAddr,
+    pub in_flight: usize,
+    pub consecutive_failures: u32,
+    pub ejected: bool,
+}
+
+/// Proxy spreading requests over the binary ports of several nodes.
+///
+/// Nodes failing `ejection_threshold` consecutive requests or health checks are excluded from
+/// selection for `ejection_period_secs`, unless all nodes are ejected.  Get requests failing
+/// other than with a node error are retried on another node, transaction commands are not as
+/// they may have reached the node.
+///
+/// Must be instantiated from within a tokio runtime.
+pub struct PooledProxy {
+    config: PoolConfig,
+    members: Arc<Vec<PoolMember>>,
+    next_member: AtomicUsize,
+}
+
+impl PooledProxy {
+    /// Creates a proxy per pooled address, each configured as per `proxy_config`.
+    pub fn new(proxy_config: ProxyConfig, config: PoolConfig) -> Result<Self, ConfigError> {
+        config.validate()?;
+        let proxies = config
+            .addresses
+            .iter()
+            .map(|address| {
+                let proxy_config = ProxyConfig {
+                    address: *address,
+                    ..proxy_config.clone()
+                };
+                let proxy: Arc<dyn Proxy> = Arc::new(RemoteProxy::new(proxy_config));
+                (*address, proxy)
+            })
+            .collect();
+
+        PooledProxy::from_proxies(proxies, config)
+    }
+
+    /// Pools the given proxies, ignoring `config.addresses`.
+    pub fn from_proxies(
+        proxies: Vec<(SocketAddr, Arc<dyn Proxy>)>,
+        config: PoolConfig,
+    ) -> Result<Self, ConfigError> {
+        let config = PoolConfig {
+            addresses: proxies.iter().map(|(address, _)| *address).collect(),
+            ..config
+        };
+        config.validate()?;
+        let members = proxies
+            .into_iter()
+            .map(|(address, proxy)| PoolMember {
+                address,
+                proxy,
+                in_flight: AtomicUsize::new(0),
+                consecutive_failures: AtomicU32::new(0),
+                ejected_until: Mutex::new(None),
+            })
+            .collect();
+        let members = Arc::new(members);
+        if config.health_check_interval_secs > 0 {
+            tokio::spawn(run_health_checks(Arc::downgrade(&members), config.clone()));
+        }
+
+        Ok(PooledProxy {
+            config,
+            members,
+            next_member: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn config(&self) -> &PoolConfig {
+        &self.config
+    }
+
+    /// Returns the health of each node, in pool order.
+    pub fn status(&self) -> Vec<PoolMemberStatus> {
+        let now = Instant::now();
+        self.members
+            .iter()
+            .map(|member| PoolMemberStatus {
+                address: member.address,
+                in_flight: member.in_flight.load(Ordering::Relaxed),
+                consecutive_failures: member.consecutive_failures.load(Ordering::Relaxed),
+                ejected: member.is_ejected(now),
+            })
+            .collect()
+    }
+
+    /// Selects a node not yet tried, preferring those not ejected.
+    fn select(&self, tried: &[usize]) -> Option<usize> {
+        let count = self.members.len();
+        let start = self.next_member.fetch_add(1, Ordering::Relaxed) % count;
+        let now = Instant::now();
+        let untried: Vec<usize> = (0..count)
+            .map(|offset| (start + offset) % count)
+            .filter(|index| !tried.contains(index))
+            .collect();
+        let healthy: Vec<usize> = untried
+            .iter()
+            .copied()
+            .filter(|index| !self.members[*index].is_ejected(now))
+            .collect();
+        let candidates = if healthy.is_empty() { untried } else { healthy };
+        match self.config.selection {
+            SelectionStrategy::RoundRobin => candidates.first().copied(),
+            SelectionStrategy::LeastInFlight => candidates
+                .iter()
+                .copied()
+                .min_by_key(|index| self.members[*index].in_flight.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+#[async_trait]
+impl Proxy for PooledProxy {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "binary_pool_dispatch",
+            skip_all,
+            fields(request_kind = request_kind(&req)),
+            err
+        )
+    )]
+    async fn dispatch(&self, req: Command) -> Result<BinaryResponseAndRequest, BinaryError> {
+        let get_request = match req {
+            Command::Get(get_request) => get_request,
+            req => {
+                let index = self.select(&[]).expect("pool is never empty");
+                return self.members[index].dispatch(req, &self.config).await;
+            }
+        };
+
+        let mut tried = vec![];
+        loop {
+            let index = self.select(&tried).expect("an untried node remains");
+            tried.push(index);
+            let result = self.members[index]
+                .dispatch(Command::Get(get_request.clone()), &self.config)
+                .await;
+            match result {
+                Err(BinaryError::Transport(_) | BinaryError::Protocol(_))
+                    if tried.len() <= self.config.max_retries
+                        && tried.len() < self.members.len() =>
+                {
+                    trace_event!(
+                        debug,
+                        endpoint = %self.members[index].address,
+                        "retrying request on another node"
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Periodically probes each node until the pool is dropped.
+async fn run_health_checks(members: Weak<Vec<PoolMember>>, config: PoolConfig) {
+    let probe: GetRequest = InformationRequest::Uptime
+        .try_into()
+        .expect("uptime request should encode");
+    let mut interval = time::interval(Duration::from_secs(config.health_check_interval_secs));
+    interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+    // First tick completes immediately.
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let Some(members) = members.upgrade() else {
+            return;
+        };
+        let probes = members
+            .iter()
+            .map(|member| member.dispatch(Command::Get(probe.clone()), &config));
+        futures::future::join_all(probes).await;
+    }
+}
@@ -0,0 +1,473 @@
+use async_trait::async_trait;
+use casper_types::bytesrepr::ToBytes;
+use futures::{
+    stream::{SplitSink, SplitStream},
+    SinkExt, StreamExt,
+};
+use l1_binary_port::{
+    BinaryMessage, BinaryMessageCodec, BinaryResponseAndRequest, Command, CommandHeader,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    future::Future,
+    io,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, oneshot, Notify},
+    time::{self, Instant},
+};
+use tokio_util::codec::Framed;
+
+#[cfg(feature = "tracing")]
+use super::codec::request_kind;
+use super::{
+    codec::{decode_response_and_id, encode_request},
+    config::ProxyConfig,
+    proxy::Proxy,
+};
+use crate::{
+    api::node::binary::error::{BinaryError, ProtocolError, TransportError},
+    utils::trace::trace_event,
+};
+
+type Connection = Framed<TcpStream, BinaryMessageCodec>;
+type ConnectFuture<'a> = Pin<Box<dyn Future<Output = io::Result<Connection>> + Send + 'a>>;
+type Responder = oneshot::Sender<Result<BinaryResponseAndRequest, BinaryError>>;
+
+/// A request queued for dispatch by the connection manager.
+struct PendingRequest {
+    request: Command,
+    responder: Responder,
+    /// Caller's span, recording the id under which the request is written.
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+/// A request awaiting the connection, timing out at `deadline`.
+struct WaitingRequest {
+    deadline: Instant,
+    pending: PendingRequest,
+}
+
+/// A request written to the connection and awaiting a response.
+struct InFlightRequest {
+    deadline: Instant,
+    /// `None` once the request has timed out, its response is then discarded upon arrival.
+    responder: Option<Responder>,
+}
+
+impl InFlightRequest {
+    fn has_expired(&self) -> bool {
+        self.responder.is_none()
+    }
+}
+
+/// Proxy over a long-lived TCP connection to a node's binary port.
+///
+/// Requests are queued (up to `request_buffer_size`) for a connection manager task, which writes up
+/// to `request_limit` requests to the connection, each with a distinct id, and routes responses to
+/// callers by the id the node echoes. A request which times out keeps its slot until its response
+/// arrives, unless timed out requests occupy every slot, in which case the connection is deemed
+/// stuck and dropped. Requests whose caller has given up are skipped if not yet written, otherwise
+/// release their slot, their response being discarded upon arrival. Upon failure the connection is
+/// dropped and re-established, with exponential backoff, once a request awaits it. Requests time
+/// out after `message_timeout_secs` whether awaiting the connection or a response.
+///
+/// Must be instantiated from within a tokio runtime.
+pub struct RemoteProxy {
+    config: ProxyConfig,
+    sender: mpsc::Sender<PendingRequest>,
+    /// Notifies the connection manager of callers giving up on their request.
+    cancelled: Arc<Notify>,
+}
+
+impl RemoteProxy {
+    pub fn new(config: ProxyConfig) -> Self {
+        let (sender, receiver) = mpsc::channel(config.request_buffer_size.max(1));
+        let cancelled = Arc::new(Notify::new());
+        tokio::spawn(run_connection_manager(
+            config.clone(),
+            receiver,
+            cancelled.clone(),
+        ));
+
+        RemoteProxy {
+            config,
+            sender,
+            cancelled,
+        }
+    }
+
+    pub fn config(&self) -> &ProxyConfig {
+        &self.config
+    }
+}
+
+#[async_trait]
+impl Proxy for RemoteProxy {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "binary_dispatch",
+            skip_all,
+            fields(
+                endpoint = %self.config.address,
+                request_kind = request_kind(&req),
+                request_id = tracing::field::Empty,
+            ),
+            err
+        )
+    )]
+    async fn dispatch(&self, req: Command) -> Result<BinaryResponseAndRequest, BinaryError> {
+        let size = CommandHeader::new(req.tag(), 0).serialized_length() + req.serialized_length();
+        if size > self.config.max_message_size_bytes as usize {
+            return Err(BinaryError::from(l1_binary_port::Error::RequestTooLarge {
+                allowed: self.config.max_message_size_bytes,
+                got: size as u32,
+            }));
+        }
+
+        // Wait for room within request buffer.
+        let (responder, response) = oneshot::channel();
+        let pending = PendingRequest {
+            request: req,
+            responder,
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::current(),
+        };
+        let access_timeout = Duration::from_secs(self.config.client_access_timeout_secs);
+        match time::timeout(access_timeout, self.sender.send(pending)).await {
+            Ok(Ok(())) => (),
+            Ok(Err(_)) => return Err(TransportError::ProxyShutdown.into()),
+            Err(_) => return Err(TransportError::ClientAccessTimeout.into()),
+        }
+
+        let guard = CancelGuard(Some(&self.cancelled));
+        let response = response
+            .await
+            .map_err(|_| BinaryError::from(TransportError::ProxyShutdown))?;
+        guard.disarm();
+        response
+    }
+}
+
+/// Notifies the connection manager if dropped before disarmed, i.e. if the caller gives up.
+struct CancelGuard<'a>(Option<&'a Notify>);
+
+impl CancelGuard<'_> {
+    fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for CancelGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(cancelled) = self.0 {
+            cancelled.notify_one();
+        }
+    }
+}
+
+/// Owns the connection to the node, writing queued requests & routing responses to callers.
+async fn run_connection_manager(
+    config: ProxyConfig,
+    mut receiver: mpsc::Receiver<PendingRequest>,
+    cancelled: Arc<Notify>,
+) {
+    let request_limit = usize::from(config.request_limit.max(1));
+    // Keeps an id free for each slot, despite abandoned requests.
+    let abandoned_limit = usize::from(u16::MAX) + 1 - request_limit;
+    let message_timeout = Duration::from_secs(config.message_timeout_secs);
+    let mut writer: Option<SplitSink<Connection, BinaryMessage>> = None;
+    let mut reader: Option<SplitStream<Connection>> = None;
+    let mut connecting: Option<ConnectFuture<'_>> = None;
+    let mut waiting: VecDeque<WaitingRequest> = VecDeque::new();
+    let mut in_flight: HashMap<u16, InFlightRequest> = HashMap::new();
+    // Ids of written requests whose caller gave up, reserved until answered.
+    let mut abandoned: HashSet<u16> = HashSet::new();
+    let mut next_request_id: u16 = 0;
+
+    loop {
+        // Write requests awaiting the connection, or establish it if none.
+        while writer.is_some() {
+            let Some(WaitingRequest { deadline, pending }) = waiting.pop_front() else {
+                break;
+            };
+            if pending.responder.is_closed() {
+                continue;
+            }
+            let request_id = allocate_request_id(&mut next_request_id, &in_flight, &abandoned);
+            #[cfg(feature = "tracing")]
+            pending.span.record("request_id", request_id);
+            trace_event!(trace, parent: &pending.span, "writing request");
+            let message = match encode_request(&pending.request, request_id) {
+                Ok(message) => message,
+                Err(err) => {
+                    let _ = pending.responder.send(Err(err));
+                    continue;
+                }
+            };
+            let sink = writer.as_mut().expect("connection established");
+            match time::timeout(message_timeout, sink.send(message)).await {
+                Ok(Ok(())) => {
+                    in_flight.insert(
+                        request_id,
+                        InFlightRequest {
+                            deadline,
+                            responder: Some(pending.responder),
+                        },
+                    );
+                }
+                Ok(Err(err)) => {
+                    let _ = pending.responder.send(Err(err.into()));
+                    fail_in_flight(&mut in_flight, |_| TransportError::ConnectionClosed.into());
+                    (writer, reader) = (None, None);
+                }
+                Err(_) => {
+                    let _ = pending
+                        .responder
+                        .send(Err(TransportError::MessageTimeout.into()));
+                    fail_in_flight(&mut in_flight, |_| TransportError::ConnectionClosed.into());
+                    (writer, reader) = (None, None);
+                }
+            }
+        }
+        if writer.is_none() && connecting.is_none() && !waiting.is_empty() {
+            connecting = Some(Box::pin(connect_with_backoff(&config)));
+        }
+
+        let next_deadline = in_flight
+            .values()
+            .filter(|request| !request.has_expired())
+            .map(|request| request.deadline)
+            .chain(waiting.iter().map(|request| request.deadline))
+            .min();
+        tokio::select! {
+            // Observe connection closure before writing further requests.
+            biased;
+
+            frame = next_frame(&mut reader), if reader.is_some() => {
+                let message = match frame {
+                    Some(Ok(message)) => message,
+                    // Closed, or an undecodable frame: reconnect upon next request.
+                    _ => {
+                        trace_event!(warn, endpoint = %config.address, "binary port connection closed");
+                        fail_in_flight(&mut in_flight, |_| TransportError::ConnectionClosed.into());
+                        (writer, reader) = (None, None);
+                        continue;
+                    }
+                };
+                match decode_response_and_id(&message) {
+                    Ok((request_id, _)) if abandoned.remove(&request_id) => {}
+                    Ok((request_id, response)) => match in_flight.remove(&request_id) {
+                        Some(request) => {
+                            if let Some(responder) = request.responder {
+                                let _ = responder.send(Ok(response));
+                            }
+                        }
+                        None => {
+                            // Node answered a request never made, hence responses cannot be
+                            // trusted to match requests.
+                            trace_event!(
+                                warn,
+                                endpoint = %config.address,
+                                request_id,
+                                "response to unknown request"
+                            );
+                            fail_in_flight(&mut in_flight, |expected| {
+                                ProtocolError::RequestIdMismatch { expected, got: request_id }
+                                    .into()
+                            });
+                            (writer, reader) = (None, None);
+                        }
+                    },
+                    Err(_err) => {
+                        trace_event!(
+                            warn,
+                            endpoint = %config.address,
+                            error = %_err,
+                            "undecodable response"
+                        );
+                        fail_in_flight(&mut in_flight, |_| TransportError::ConnectionClosed.into());
+                        (writer, reader) = (None, None);
+                    }
+                }
+            }
+            _ = cancelled.notified() => {
+                waiting.retain(|request| !request.pending.responder.is_closed());
+                let closed: Vec<u16> = in_flight
+                    .iter()
+                    .filter(|(_, request)| {
+                        request.responder.as_ref().is_some_and(|responder| responder.is_closed())
+                    })
+                    .map(|(request_id, _)| *request_id)
+                    .collect();
+                for request_id in closed {
+                    in_flight.remove(&request_id);
+                    abandoned.insert(request_id);
+                }
+                if abandoned.len() >= abandoned_limit {
+                    trace_event!(
+                        warn,
+                        endpoint = %config.address,
+                        "abandoned requests unanswered, dropping connection"
+                    );
+                    fail_in_flight(&mut in_flight, |_| TransportError::ConnectionClosed.into());
+                    abandoned.clear();
+                    (writer, reader) = (None, None);
+                }
+            }
+            connection = next_connection(&mut connecting), if connecting.is_some() => {
+                connecting = None;
+                match connection {
+                    Ok(connection) => {
+                        abandoned.clear();
+                        let (sink, stream) = connection.split();
+                        writer = Some(sink);
+                        reader = Some(stream);
+                    }
+                    Err(err) => {
+                        for request in waiting.drain(..) {
+                            let error = io::Error::new(err.kind(), err.to_string());
+                            let _ = request
+                                .pending
+                                .responder
+                                .send(Err(TransportError::ConnectionError(error).into()));
+                        }
+                    }
+                }
+            }
+            pending = receiver.recv(), if in_flight.len() + waiting.len() < request_limit => {
+                let Some(pending) = pending else {
+                    // All proxy handles dropped.
+                    return;
+                };
+                if !pending.responder.is_closed() {
+                    waiting.push_back(WaitingRequest {
+                        deadline: Instant::now() + message_timeout,
+                        pending,
+                    });
+                }
+            }
+            _ = sleep_until(next_deadline), if next_deadline.is_some() => {
+                let now = Instant::now();
+                for request in in_flight.values_mut() {
+                    if request.deadline <= now {
+                        if let Some(responder) = request.responder.take() {
+                            let _ = responder.send(Err(TransportError::MessageTimeout.into()));
+                        }
+                    }
+                }
+                let expired = in_flight.values().filter(|request| request.has_expired()).count();
+                if expired >= request_limit {
+                    trace_event!(
+                        warn,
+                        endpoint = %config.address,
+                        "all in-flight requests timed out, dropping connection"
+                    );
+                    in_flight.clear();
+                    (writer, reader) = (None, None);
+                }
+                // Requests time out whilst awaiting the connection too, which is then no longer
+                // attempted unless others await it.
+                while waiting.front().is_some_and(|request| request.deadline <= now) {
+                    let request = waiting.pop_front().expect("front request exists");
+                    let _ = request
+                        .pending
+                        .responder
+                        .send(Err(TransportError::MessageTimeout.into()));
+                }
+                if waiting.is_empty() {
+                    connecting = None;
+                }
+            }
+        }
+    }
+}
+
+/// Returns the next request id neither in flight nor abandoned.
+fn allocate_request_id(
+    next_request_id: &mut u16,
+    in_flight: &HashMap<u16, InFlightRequest>,
+    abandoned: &HashSet<u16>,
+) -> u16 {
+    loop {
+        let request_id = *next_request_id;
+        *next_request_id = next_request_id.wrapping_add(1);
+        if !in_flight.contains_key(&request_id) && !abandoned.contains(&request_id) {
+            return request_id;
+        }
+    }
+}
+
+/// Connects to the node, retrying with exponential backoff as per configuration.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "binary_connect", skip_all, fields(endpoint = %config.address), err)
+)]
+async fn connect_with_backoff(config: &ProxyConfig) -> io::Result<Connection> {
+    let backoff = &config.exponential_backoff;
+    let mut delay_ms = backoff.initial_delay_ms;
+    let mut attempt = 1;
+    loop {
+        match TcpStream::connect(config.address).await {
+            Ok(stream) => {
+                trace_event!(debug, attempt, "connected to binary port");
+                return Ok(Framed::new(
+                    stream,
+                    BinaryMessageCodec::new(config.max_message_size_bytes),
+                ));
+            }
+            Err(err) => {
+                trace_event!(debug, attempt, error = %err, "connection attempt failed");
+                attempt += 1;
+                if !backoff.max_attempts.can_attempt(attempt) {
+                    return Err(err);
+                }
+            }
+        }
+        time::sleep(Duration::from_millis(delay_ms)).await;
+        delay_ms = delay_ms
+            .saturating_mul(backoff.coefficient)
+            .min(backoff.max_delay_ms);
+    }
+}
+
+async fn next_connection(connecting: &mut Option<ConnectFuture<'_>>) -> io::Result<Connection> {
+    match connecting.as_mut() {
+        Some(connection) => connection.await,
+        None => futures::future::pending().await,
+    }
+}
+
+async fn next_frame(
+    reader: &mut Option<SplitStream<Connection>>,
+) -> Option<Result<BinaryMessage, l1_binary_port::Error>> {
+    match reader.as_mut() {
+        Some(stream) => stream.next().await,
+        None => futures::future::pending().await,
+    }
+}
+
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => time::sleep_until(deadline).await,
+        None => futures::future::pending().await,
+    }
+}
+
+/// Fails every in-flight request still awaited, given its id.
+fn fail_in_flight(
+    in_flight: &mut HashMap<u16, InFlightRequest>,
+    error: impl Fn(u16) -> BinaryError,
+) {
+    for (request_id, request) in in_flight.drain() {
+        if let Some(responder) = request.responder {
+            let _ = responder.send(Err(error(request_id)));
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    Block, BlockBody, BlockHash, BlockHeader, BlockIdentifier, Digest, Key, KeyTag, StoredValue,
+    Tagged, Transaction, TransactionHash,
+};
+use futures::{SinkExt, StreamExt};
+use l1_binary_port::{
+    BinaryMessage, BinaryMessageCodec, BinaryResponse, BinaryResponseAndRequest, Command,
+    CommandHeader, CommandTag, ErrorCode, GetRequest, GlobalStateEntityQualifier,
+    GlobalStateQueryResult, InformationRequest, InformationRequestTag, NetworkName, RecordId,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    io,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    time::Duration,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::broadcast,
+    task::JoinHandle,
+};
+use tokio_util::codec::Framed;
+
+use super::proxy::constants::DEFAULT_MAX_PAYLOAD_SIZE;
+
+/// In-memory chain data served by a fake binary port server.
+///
+/// Global state is flat: items are served whatever the requested state root, and only without a
+/// path.  Merkle proofs are empty.
+#[derive(Clone, Debug, Default)]
+pub struct FakeStore {
+    network_name: String,
+    block_headers: BTreeMap<u64, BlockHeader>,
+    block_heights: HashMap<BlockHash, u64>,
+    block_bodies: HashMap<Digest, BlockBody>,
+    transactions: HashMap<TransactionHash, Transaction>,
+    items: BTreeMap<Key, StoredValue>,
+}
+
+impl FakeStore {
+    pub fn new(network_name: impl Into<String>) -> Self {
+        FakeStore {
+            network_name: network_name.into(),
+            ..FakeStore::default()
+        }
+    }
+
+    pub fn with_block(mut self, block: Block) -> Self {
+        self.insert_block(block);
+        self
+    }
+
+    pub fn with_transaction(mut self, transaction: Transaction) -> Self {
+        self.insert_transaction(transaction);
+        self
+    }
+
+    pub fn with_item(mut self, key: Key, value: StoredValue) -> Self {
+        self.insert_item(key, value);
+        self
+    }
+
+    pub fn insert_block(&mut self, block: Block) {
+        self.block_heights.insert(*block.hash(), block.height());
+        self.block_headers
+            .insert(block.height(), block.clone_header());
+        self.block_bodies
+            .insert(*block.body_hash(), block.take_body());
+    }
+
+    pub fn insert_transaction(&mut self, transaction: Transaction) {
+        self.transactions.insert(transaction.hash(), transaction);
+    }
+
+    pub fn insert_item(&mut self, key: Key, value: StoredValue) {
+        self.items.insert(key, value);
+    }
+
+    pub fn network_name(&self) -> &str {
+        &self.network_name
+    }
+
+    pub fn transaction(&self, hash: &TransactionHash) -> Option<&Transaction> {
+        self.transactions.get(hash)
+    }
+
+    fn block_header(&self, block_id: Option<BlockIdentifier>) -> Option<&BlockHeader> {
+        match block_id {
+            None => self.block_headers.values().next_back(),
+            Some(BlockIdentifier::Height(height)) => self.block_headers.get(&height),
+            Some(BlockIdentifier::Hash(hash)) => self
+                .block_heights
+                .get(&hash)
+                .and_then(|height| self.block_headers.get(height)),
+        }
+    }
+
+    /// Answers a request as the node would.
+    fn respond(&mut self, command: Command) -> BinaryResponse {
+        match command {
+            Command::Get(GetRequest::Record {
+                record_type_tag,
+                key,
+            }) => self.respond_to_record(record_type_tag, &key),
+            Command::Get(GetRequest::Information { info_type_tag, key }) => {
+                self.respond_to_information(info_type_tag, &key)
+            }
+            Command::Get(GetRequest::State(req)) => match req.destructure().1 {
+                GlobalStateEntityQualifier::Item { base_key, path } if path.is_empty() => {
+                    BinaryResponse::from_option(
+                        self.items
+                            .get(&base_key)
+                            .map(|value| GlobalStateQueryResult::new(value.clone(), vec![])),
+                    )
+                }
+                GlobalStateEntityQualifier::AllItems { key_tag } => BinaryResponse::from_value(
+                    self.items
+                        .iter()
+                        .filter(|(key, _)| Tagged::<KeyTag>::tag(*key) == key_tag)
+                        .map(|(_, value)| value.clone())
+                        .collect::<Vec<_>>(),
+                ),
+                _ => BinaryResponse::new_error(ErrorCode::UnsupportedRequest),
+            },
+            Command::Get(GetRequest::Trie { .. }) => {
+                BinaryResponse::new_error(ErrorCode::UnsupportedRequest)
+            }
+            Command::TryAcceptTransaction { transaction } => {
+                self.insert_transaction(transaction);
+                BinaryResponse::new_empty()
+            }
+            Command::TrySpeculativeExec { .. } => {
+                BinaryResponse::new_error(ErrorCode::FunctionDisabled)
+            }
+        }
+    }
+
+    fn respond_to_record(&self, record_type_tag: u16, key: &[u8]) -> BinaryResponse {
+        let Ok(record_id) = RecordId::try_from(record_type_tag) else {
+            return BinaryResponse::new_error(ErrorCode::UnsupportedRequest);
+        };
+        match record_id {
+            RecordId::BlockHeader => match BlockHash::from_bytes(key) {
+                Ok((hash, _)) => BinaryResponse::from_option(
+                    self.block_header(Some(BlockIdentifier::Hash(hash)))
+                        .cloned(),
+                ),
+                Err(_) => BinaryResponse::new_error(ErrorCode::BadRequest),
+            },
+            RecordId::BlockBody => match Digest::from_bytes(key) {
+                Ok((body_hash, _)) => {
+                    BinaryResponse::from_option(self.block_bodies.get(&body_hash).cloned())
+                }
+                Err(_) => BinaryResponse::new_error(ErrorCode::BadRequest),
+            },
+            RecordId::Transaction => match TransactionHash::from_bytes(key) {
+                Ok((hash, _)) => BinaryResponse::from_option(self.transactions.get(&hash).cloned()),
+                Err(_) => BinaryResponse::new_error(ErrorCode::BadRequest),
+            },
+            _ => BinaryResponse::new_empty(),
+        }
+    }
+
+    fn respond_to_information(&self, info_type_tag: u16, key: &[u8]) -> BinaryResponse {
+        let request = InformationRequestTag::try_from(info_type_tag)
+            .ok()
+            .and_then(|tag| InformationRequest::try_from((tag, key)).ok());
+        match request {
+            Some(InformationRequest::BlockHeader(block_id)) => {
+                BinaryResponse::from_option(self.block_header(block_id).cloned())
+            }
+            Some(InformationRequest::NetworkName) => {
+                BinaryResponse::from_value(NetworkName::new(self.network_name.clone()))
+            }
+            Some(_) => BinaryResponse::new_error(ErrorCode::UnsupportedRequest),
+            None => BinaryResponse::new_error(ErrorCode::MalformedInformationRequest),
+        }
+    }
+}
+
+/// Fake binary port server configuration.
+#[derive(Clone, Debug)]
+pub struct FakeServerConfig {
+    /// Maximum size of a message in bytes, larger requests cause the connection to be dropped.
+    pub max_message_size_bytes: u32,
+    /// Delay before each response is written.
+    pub response_delay: Duration,
+}
+
+impl Default for FakeServerConfig {
+    fn default() -> Self {
+        FakeServerConfig {
+            max_message_size_bytes: DEFAULT_MAX_PAYLOAD_SIZE,
+            response_delay: Duration::ZERO,
+        }
+    }
+}
+
+/// State shared between the server handle and its connection tasks.
+struct Shared {
+    config: FakeServerConfig,
+    store: RwLock<FakeStore>,
+    response_delay: Mutex<Duration>,
+    silent: AtomicBool,
+    connection_count: AtomicUsize,
+    request_count: AtomicUsize,
+    disconnect: broadcast::Sender<()>,
+}
+
+/// Local TCP server speaking the node's binary port framing, answering from an in-memory store.
+///
+/// Requests are answered in the order received on each connection, with the request echoed as
+/// the node does.  Behaviour can be altered whilst running to exercise client timeouts and
+/// reconnects.  The server stops when dropped.
+pub struct FakeServer {
+    address: SocketAddr,
+    shared: Arc<Shared>,
+    listener: JoinHandle<()>,
+}
+
+impl FakeServer {
+    /// Starts a server on an ephemeral localhost port.
+    pub async fn start(store: FakeStore) -> io::Result<Self> {
+        FakeServer::start_with_config(store, FakeServerConfig::default()).await
+    }
+
+    pub async fn start_with_config(store: FakeStore, config: FakeServerConfig) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let (disconnect, _) = broadcast::channel(1);
+        let shared = Arc::new(Shared {
+            response_delay: Mutex::new(config.response_delay),
+            config,
+            store: RwLock::new(store),
+            silent: AtomicBool::new(false),
+            connection_count: AtomicUsize::new(0),
+            request_count: AtomicUsize::new(0),
+            disconnect,
+        });
+        let listener = tokio::spawn(accept_connections(listener, shared.clone()));
+
+        Ok(FakeServer {
+            address,
+            shared,
+            listener,
+        })
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Alters the store whilst running.
+    pub fn update_store(&self, update: impl FnOnce(&mut FakeStore)) {
+        update(&mut self.shared.store.write().expect("fake store lock poisoned"));
+    }
+
+    /// Returns a copy of the store, e.g. to inspect accepted transactions.
+    pub fn store(&self) -> FakeStore {
+        self.shared
+            .store
+            .read()
+            .expect("fake store lock poisoned")
+            .clone()
+    }
+
+    pub fn set_response_delay(&self, delay: Duration) {
+        *self
+            .shared
+            .response_delay
+            .lock()
+            .expect("fake server lock poisoned") = delay;
+    }
+
+    /// Whether requests are read but left unanswered.
+    pub fn set_silent(&self, silent: bool) {
+        self.shared.silent.store(silent, Ordering::Relaxed);
+    }
+
+    /// Closes all open connections, clients may reconnect.
+    pub fn disconnect_all(&self) {
+        let _ = self.shared.disconnect.send(());
+    }
+
+    /// Returns the number of connections accepted so far.
+    pub fn connection_count(&self) -> usize {
+        self.shared.connection_count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of requests received so far, whether answered or not.
+    pub fn request_count(&self) -> usize {
+        self.shared.request_count.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        self.listener.abort();
+        self.disconnect_all();
+    }
+}
+
+async fn accept_connections(listener: TcpListener, shared: Arc<Shared>) {
+    while let Ok((socket, _)) = listener.accept().await {
+        shared.connection_count.fetch_add(1, Ordering::Relaxed);
+        tokio::spawn(serve_connection(socket, shared.clone()));
+    }
+}
+
+async fn serve_connection(socket: TcpStream, shared: Arc<Shared>) {
+    let mut disconnect = shared.disconnect.subscribe();
+    let mut framed = Framed::new(
+        socket,
+        BinaryMessageCodec::new(shared.config.max_message_size_bytes),
+    );
+    loop {
+        let message = tokio::select! {
+            message = framed.next() => match message {
+                Some(Ok(message)) => message,
+                // Closed by client, or request too large or malformed.
+                _ => return,
+            },
+            _ = disconnect.recv() => return,
+        };
+        shared.request_count.fetch_add(1, Ordering::Relaxed);
+        if shared.silent.load(Ordering::Relaxed) {
+            continue;
+        }
+
+        let delay = *shared
+            .response_delay
+            .lock()
+            .expect("fake server lock poisoned");
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        let response = handle_message(&message, &shared.store);
+        let response = match response.to_bytes() {
+            Ok(bytes) => BinaryMessage::new(bytes),
+            Err(_) => return,
+        };
+        if framed.send(response).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Answers a request message, echoing the request as does the node.
+fn handle_message(message: &BinaryMessage, store: &RwLock<FakeStore>) -> BinaryResponseAndRequest {
+    let request = Bytes::from(message.payload().to_vec());
+    let Ok((header, remainder)) = CommandHeader::from_bytes(message.payload()) else {
+        let response = BinaryResponse::new_error(ErrorCode::MalformedCommandHeader);
+        return BinaryResponseAndRequest::new(response, request);
+    };
+    let command = CommandTag::try_from(header.type_tag())
+        .ok()
+        .and_then(|tag| Command::try_from((tag, remainder)).ok());
+    let response = match command {
+        Some(command) => store
+            .write()
+            .expect("fake store lock poisoned")
+            .respond(command),
+        None => BinaryResponse::new_error(ErrorCode::MalformedCommand),
+    };
+
+    BinaryResponseAndRequest::new(response, request)
+}
@@ -0,0 +1,188 @@
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    global_state::{Pointer, TrieMerkleProof},
+    ApprovalsHash, BlockHash, Key, StoredValue,
+};
+use l1_binary_port::{PayloadEntity, ResponseType};
+use serde::Deserialize;
+
+pub use l1_binary_port::{
+    BalanceResponse, ConsensusStatus, DictionaryItemIdentifier, DictionaryQueryResult,
+    EraIdentifier, ErrorCode, GlobalStateQueryResult, KeyPrefix, NodeStatus, PurseIdentifier,
+    RewardResponse, SpeculativeExecutionResult,
+};
+
+/// Hashes of the approvals of each transaction within a block, with a merkle proof of their
+/// inclusion within global state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApprovalsHashes {
+    pub block_hash: BlockHash,
+    pub approvals_hashes: Vec<ApprovalsHash>,
+    pub merkle_proof_approvals: TrieMerkleProof<Key, StoredValue>,
+}
+
+impl PayloadEntity for ApprovalsHashes {
+    const RESPONSE_TYPE: ResponseType = ResponseType::ApprovalsHashes;
+}
+
+impl ToBytes for ApprovalsHashes {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        self.write_bytes(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        self.block_hash.write_bytes(writer)?;
+        self.approvals_hashes.write_bytes(writer)?;
+        self.merkle_proof_approvals.write_bytes(writer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.block_hash.serialized_length()
+            + self.approvals_hashes.serialized_length()
+            + self.merkle_proof_approvals.serialized_length()
+    }
+}
+
+impl FromBytes for ApprovalsHashes {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (block_hash, remainder) = BlockHash::from_bytes(bytes)?;
+        let (approvals_hashes, remainder) = Vec::<ApprovalsHash>::from_bytes(remainder)?;
+        let (merkle_proof_approvals, remainder) =
+            TrieMerkleProof::<Key, StoredValue>::from_bytes(remainder)?;
+        Ok((
+            ApprovalsHashes {
+                block_hash,
+                approvals_hashes,
+                merkle_proof_approvals,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Legacy (pre 2.0) approvals hashes, as returned bincode-encoded by the node.
+#[derive(Deserialize)]
+pub(crate) struct ApprovalsHashesV1 {
+    block_hash: BlockHash,
+    approvals_hashes: Vec<ApprovalsHash>,
+    merkle_proof_approvals: TrieMerkleProof<Key, StoredValue>,
+}
+
+impl PayloadEntity for ApprovalsHashesV1 {
+    const RESPONSE_TYPE: ResponseType = ResponseType::ApprovalsHashesV1;
+}
+
+impl From<ApprovalsHashesV1> for ApprovalsHashes {
+    fn from(legacy: ApprovalsHashesV1) -> Self {
+        ApprovalsHashes {
+            block_hash: legacy.block_hash,
+            approvals_hashes: legacy.approvals_hashes,
+            merkle_proof_approvals: legacy.merkle_proof_approvals,
+        }
+    }
+}
+
+/// Number of children of a trie node.
+const TRIE_RADIX: usize = 256;
+
+const TRIE_LEAF_TAG: u8 = 0;
+const TRIE_NODE_TAG: u8 = 1;
+const TRIE_EXTENSION_TAG: u8 = 2;
+
+/// Node of the global state trie, as returned raw by the node.
+///
+/// The trie is keyed by serialized `Key`, each byte of which selects a child of a node, with
+/// extensions skipping bytes shared by all keys beneath them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Trie {
+    /// Entry of global state.
+    Leaf { key: Key, value: Box<StoredValue> },
+    /// Children, by index, i.e. the next byte of their keys.
+    Node { pointers: Vec<(u8, Pointer)> },
+    /// Bytes shared by the keys of all entries beneath the pointed to trie.
+    Extension { affix: Bytes, pointer: Pointer },
+}
+
+impl ToBytes for Trie {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        self.write_bytes(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        match self {
+            Trie::Leaf { key, value } => {
+                writer.push(TRIE_LEAF_TAG);
+                key.write_bytes(writer)?;
+                value.write_bytes(writer)
+            }
+            Trie::Node { pointers } => {
+                // Serialized as the full block of optional pointers.
+                let mut block: [Option<Pointer>; TRIE_RADIX] = [None; TRIE_RADIX];
+                for (index, pointer) in pointers {
+                    block[usize::from(*index)] = Some(*pointer);
+                }
+                writer.push(TRIE_NODE_TAG);
+                block
+                    .iter()
+                    .try_for_each(|pointer| pointer.write_bytes(writer))
+            }
+            Trie::Extension { affix, pointer } => {
+                writer.push(TRIE_EXTENSION_TAG);
+                affix.write_bytes(writer)?;
+                pointer.write_bytes(writer)
+            }
+        }
+    }
+
+    fn serialized_length(&self) -> usize {
+        1 + match self {
+            Trie::Leaf { key, value } => key.serialized_length() + value.serialized_length(),
+            Trie::Node { pointers } => {
+                TRIE_RADIX
+                    + pointers
+                        .iter()
+                        .map(|(_, pointer)| pointer.serialized_length())
+                        .sum::<usize>()
+            }
+            Trie::Extension { affix, pointer } => {
+                affix.serialized_length() + pointer.serialized_length()
+            }
+        }
+    }
+}
+
+impl FromBytes for Trie {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            TRIE_LEAF_TAG => {
+                let (key, remainder) = Key::from_bytes(remainder)?;
+                let (value, remainder) = StoredValue::from_bytes(remainder)?;
+                let value = Box::new(value);
+                Ok((Trie::Leaf { key, value }, remainder))
+            }
+            TRIE_NODE_TAG => {
+                let mut pointers = vec![];
+                let mut remainder = remainder;
+                for index in 0..=u8::MAX {
+                    let (pointer, rest) = Option::<Pointer>::from_bytes(remainder)?;
+                    if let Some(pointer) = pointer {
+                        pointers.push((index, pointer));
+                    }
+                    remainder = rest;
+                }
+                Ok((Trie::Node { pointers }, remainder))
+            }
+            TRIE_EXTENSION_TAG => {
+                let (affix, remainder) = Bytes::from_bytes(remainder)?;
+                let (pointer, remainder) = Pointer::from_bytes(remainder)?;
+                Ok((Trie::Extension { affix, pointer }, remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
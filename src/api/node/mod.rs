@@ -1,3 +1,3 @@
-pub(super) mod binary;
+pub mod binary;
 pub(super) mod rest;
 pub mod sse;
@@ -0,0 +1,29 @@
+use casper_types::bytesrepr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CesError {
+    #[error("Invalid TransactionProcessed payload: {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+
+    #[error("Event is not a TransactionProcessed event")]
+    UnexpectedEventType,
+
+    #[error("Failed to deserialize bytes: {0}")]
+    BytesreprError(#[from] bytesrepr::Error),
+
+    #[error("Message payload is not a byte payload")]
+    NonBinaryPayload,
+
+    #[error("Event name is missing the '{}' prefix: {0}", super::EVENT_NAME_PREFIX)]
+    InvalidEventName(String),
+
+    #[error("Event not found in schema: {0}")]
+    EventNotInSchema(String),
+
+    #[error("Failed to parse field '{0}' of event '{1}'")]
+    InvalidField(String, String),
+
+    #[error("Trailing bytes after parsing event: {0}")]
+    TrailingBytes(String),
+}
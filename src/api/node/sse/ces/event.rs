@@ -0,0 +1,211 @@
+use super::{
+    error::CesError,
+    messages::{MessageFilter, TransactionMessages},
+    schema::Schemas,
+    DEFAULT_EVENTS_TOPIC, EVENT_NAME_PREFIX,
+};
+use crate::api::node::sse::SseData;
+use casper_types::{
+    bytesrepr::{FromBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, RESULT_ERR_TAG, RESULT_OK_TAG},
+    contract_messages::{Message, MessagePayload},
+    BlockHash, CLType, CLValue, EntityAddr, Key, PublicKey, TransactionHash, URef, U128, U256,
+    U512,
+};
+
+/// Maximum depth of nested CL types - mirrors the limit applied by casper-types.
+const CL_TYPE_RECURSION_DEPTH: u8 = 50;
+
+/// A CES event decoded from a contract message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractEvent {
+    /// Hash of transaction during which the event was emitted.
+    pub transaction_hash: TransactionHash,
+    /// Hash of block in which the transaction was processed.
+    pub block_hash: BlockHash,
+    /// Address of emitting contract.
+    pub entity_addr: EntityAddr,
+    /// Name of topic over which the event was emitted.
+    pub topic_name: String,
+    /// Index of message within topic.
+    pub topic_index: u32,
+    /// Index of message within block.
+    pub block_index: u64,
+    /// Event name, as declared in the contract's schema.
+    pub name: String,
+    /// Event fields, in schema order.
+    pub fields: Vec<(String, CLValue)>,
+}
+
+impl ContractEvent {
+    /// Returns value of a named field.
+    pub fn field(&self, name: &str) -> Option<&CLValue> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Decodes CES events emitted by a single contract.
+#[derive(Clone, Debug)]
+pub struct Decoder {
+    schemas: Schemas,
+    filter: MessageFilter,
+}
+
+impl Decoder {
+    /// Returns a decoder of events emitted by a contract over the default CES topic.
+    pub fn new(entity_addr: EntityAddr, schemas: Schemas) -> Self {
+        Self::with_topic(entity_addr, DEFAULT_EVENTS_TOPIC, schemas)
+    }
+
+    /// Returns a decoder of events emitted by a contract over a custom topic.
+    pub fn with_topic(entity_addr: EntityAddr, topic_name: &str, schemas: Schemas) -> Self {
+        Decoder {
+            schemas,
+            filter: MessageFilter::new()
+                .with_entity_addr(entity_addr)
+                .with_topic_name(topic_name),
+        }
+    }
+
+    pub fn filter(&self) -> &MessageFilter {
+        &self.filter
+    }
+
+    /// Decodes all matching events carried by a `TransactionProcessed` event.
+    pub fn decode_event(&self, event: &SseData) -> Result<Vec<ContractEvent>, CesError> {
+        let messages = TransactionMessages::from_event(event)?;
+        messages
+            .filter(&self.filter)
+            .map(|msg| self.decode_message(&messages, msg))
+            .collect()
+    }
+
+    fn decode_message(
+        &self,
+        messages: &TransactionMessages,
+        message: &Message,
+    ) -> Result<ContractEvent, CesError> {
+        let bytes = match message.payload() {
+            MessagePayload::Bytes(bytes) => bytes.as_slice(),
+            MessagePayload::String(_) => return Err(CesError::NonBinaryPayload),
+        };
+        let (name, fields) = self.decode_payload(bytes)?;
+
+        Ok(ContractEvent {
+            transaction_hash: messages.transaction_hash,
+            block_hash: messages.block_hash,
+            entity_addr: *message.entity_addr(),
+            topic_name: message.topic_name().to_string(),
+            topic_index: message.topic_index(),
+            block_index: message.block_index(),
+            name,
+            fields,
+        })
+    }
+
+    /// Decodes a raw CES payload into an event name plus set of named fields.
+    pub fn decode_payload(
+        &self,
+        bytes: &[u8],
+    ) -> Result<(String, Vec<(String, CLValue)>), CesError> {
+        let (prefixed_name, mut stream) = String::from_bytes(bytes)?;
+        let name = prefixed_name
+            .strip_prefix(EVENT_NAME_PREFIX)
+            .ok_or_else(|| CesError::InvalidEventName(prefixed_name.clone()))?;
+        let schema = self
+            .schemas
+            .get(name)
+            .ok_or_else(|| CesError::EventNotInSchema(name.to_string()))?;
+
+        let mut fields = Vec::with_capacity(schema.fields().len());
+        for (field_name, cl_type) in schema.fields() {
+            let remainder = skip_cl_value(0, cl_type, stream)
+                .ok_or_else(|| CesError::InvalidField(field_name.clone(), name.to_string()))?;
+            let field_bytes = &stream[..stream.len() - remainder.len()];
+            fields.push((
+                field_name.clone(),
+                CLValue::from_components(cl_type.clone(), field_bytes.to_vec()),
+            ));
+            stream = remainder;
+        }
+        if !stream.is_empty() {
+            return Err(CesError::TrailingBytes(name.to_string()));
+        }
+
+        Ok((name.to_string(), fields))
+    }
+}
+
+/// Walks over a single bytesrepr encoded value of the given type, returning the remaining bytes.
+fn skip_cl_value<'a>(depth: u8, cl_type: &CLType, bytes: &'a [u8]) -> Option<&'a [u8]> {
+    if depth >= CL_TYPE_RECURSION_DEPTH {
+        return None;
+    }
+    let depth = depth + 1;
+
+    match cl_type {
+        CLType::Bool => skip::<bool>(bytes),
+        CLType::I32 => skip::<i32>(bytes),
+        CLType::I64 => skip::<i64>(bytes),
+        CLType::U8 => skip::<u8>(bytes),
+        CLType::U32 => skip::<u32>(bytes),
+        CLType::U64 => skip::<u64>(bytes),
+        CLType::U128 => skip::<U128>(bytes),
+        CLType::U256 => skip::<U256>(bytes),
+        CLType::U512 => skip::<U512>(bytes),
+        CLType::Unit => skip::<()>(bytes),
+        CLType::String => skip::<String>(bytes),
+        CLType::Key => skip::<Key>(bytes),
+        CLType::URef => skip::<URef>(bytes),
+        CLType::PublicKey => skip::<PublicKey>(bytes),
+        CLType::Option(inner) => {
+            let (tag, remainder) = u8::from_bytes(bytes).ok()?;
+            match tag {
+                OPTION_NONE_TAG => Some(remainder),
+                OPTION_SOME_TAG => skip_cl_value(depth, inner, remainder),
+                _ => None,
+            }
+        }
+        CLType::List(inner) => {
+            let (count, mut stream) = u32::from_bytes(bytes).ok()?;
+            for _ in 0..count {
+                stream = skip_cl_value(depth, inner, stream)?;
+            }
+            Some(stream)
+        }
+        CLType::ByteArray(length) => bytes.get(*length as usize..),
+        CLType::Result { ok, err } => {
+            let (tag, remainder) = u8::from_bytes(bytes).ok()?;
+            match tag {
+                RESULT_ERR_TAG => skip_cl_value(depth, err, remainder),
+                RESULT_OK_TAG => skip_cl_value(depth, ok, remainder),
+                _ => None,
+            }
+        }
+        CLType::Map { key, value } => {
+            let (count, mut stream) = u32::from_bytes(bytes).ok()?;
+            for _ in 0..count {
+                stream = skip_cl_value(depth, key, stream)?;
+                stream = skip_cl_value(depth, value, stream)?;
+            }
+            Some(stream)
+        }
+        CLType::Tuple1([t1]) => skip_cl_value(depth, t1, bytes),
+        CLType::Tuple2([t1, t2]) => {
+            let remainder = skip_cl_value(depth, t1, bytes)?;
+            skip_cl_value(depth, t2, remainder)
+        }
+        CLType::Tuple3([t1, t2, t3]) => {
+            let remainder = skip_cl_value(depth, t1, bytes)?;
+            let remainder = skip_cl_value(depth, t2, remainder)?;
+            skip_cl_value(depth, t3, remainder)
+        }
+        CLType::Any => None,
+    }
+}
+
+fn skip<T: FromBytes>(bytes: &[u8]) -> Option<&[u8]> {
+    T::from_bytes(bytes).ok().map(|(_, remainder)| remainder)
+}
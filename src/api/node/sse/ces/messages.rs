@@ -0,0 +1,61 @@
+use super::error::CesError;
+use crate::api::node::sse::SseData;
+use casper_types::{contract_messages::Message, BlockHash, EntityAddr, TransactionHash};
+use serde::Deserialize;
+
+/// Subset of a `TransactionProcessed` payload pertinent to contract messages.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TransactionMessages {
+    pub transaction_hash: TransactionHash,
+    pub block_hash: BlockHash,
+    #[serde(default)]
+    pub messages: Vec<Message>,
+}
+
+impl TransactionMessages {
+    /// Extracts messages from a `TransactionProcessed` event.
+    pub fn from_event(event: &SseData) -> Result<Self, CesError> {
+        match event {
+            SseData::TransactionProcessed(payload) => Ok(Self::deserialize(payload)?),
+            _ => Err(CesError::UnexpectedEventType),
+        }
+    }
+
+    /// Returns messages matching the filter, in the order in which they were emitted.
+    pub fn filter<'a>(&'a self, filter: &'a MessageFilter) -> impl Iterator<Item = &'a Message> {
+        self.messages.iter().filter(|msg| filter.is_match(msg))
+    }
+}
+
+/// Predicate over emitting entity address and topic name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MessageFilter {
+    entity_addr: Option<EntityAddr>,
+    topic_name: Option<String>,
+}
+
+impl MessageFilter {
+    /// Returns a filter matching all messages.
+    pub fn new() -> Self {
+        MessageFilter::default()
+    }
+
+    pub fn with_entity_addr(mut self, entity_addr: EntityAddr) -> Self {
+        self.entity_addr = Some(entity_addr);
+        self
+    }
+
+    pub fn with_topic_name(mut self, topic_name: &str) -> Self {
+        self.topic_name = Some(topic_name.to_string());
+        self
+    }
+
+    pub fn is_match(&self, message: &Message) -> bool {
+        self.entity_addr
+            .is_none_or(|addr| &addr == message.entity_addr())
+            && self
+                .topic_name
+                .as_ref()
+                .is_none_or(|name| name == message.topic_name())
+    }
+}
@@ -0,0 +1,24 @@
+//! Casper Event Standard (CES) decoding of contract messages.
+//!
+//! Casper 2.0 contracts emit events as messages on named topics.  Such messages are carried
+//! within the `messages` field of a `TransactionProcessed` event.  This module extracts those
+//! messages and decodes CES encoded payloads against a contract's event schema.
+
+pub mod error;
+pub mod event;
+pub mod messages;
+pub mod schema;
+
+pub use error::CesError;
+pub use event::{ContractEvent, Decoder};
+pub use messages::{MessageFilter, TransactionMessages};
+pub use schema::{Schema, Schemas};
+
+/// Name of topic over which CES compliant contracts emit events.
+pub const DEFAULT_EVENTS_TOPIC: &str = "__events";
+
+/// Name of named key under which CES compliant contracts store their event schema.
+pub const EVENTS_SCHEMA_NAMED_KEY: &str = "__events_schema";
+
+/// Prefix applied by CES to event names when serializing an event.
+pub const EVENT_NAME_PREFIX: &str = "event_";
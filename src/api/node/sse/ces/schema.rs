@@ -0,0 +1,73 @@
+use super::error::CesError;
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    CLType, CLValue,
+};
+use std::collections::BTreeMap;
+
+/// Ordered set of named & typed fields making up a single event.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schema(Vec<(String, CLType)>);
+
+impl Schema {
+    pub fn new() -> Self {
+        Schema(Vec::new())
+    }
+
+    /// Appends a field - fields must be declared in the order in which the contract serializes them.
+    pub fn with_field(mut self, name: &str, cl_type: CLType) -> Self {
+        self.0.push((name.to_string(), cl_type));
+        self
+    }
+
+    pub fn fields(&self) -> &[(String, CLType)] {
+        &self.0
+    }
+}
+
+impl FromBytes for Schema {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (fields, remainder) = Vec::<(String, CLType)>::from_bytes(bytes)?;
+        Ok((Schema(fields), remainder))
+    }
+}
+
+/// Set of event schemas declared by a contract, keyed by event name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schemas(BTreeMap<String, Schema>);
+
+impl Schemas {
+    pub fn new() -> Self {
+        Schemas(BTreeMap::new())
+    }
+
+    pub fn with_event(mut self, name: &str, schema: Schema) -> Self {
+        self.0.insert(name.to_string(), schema);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Schema> {
+        self.0.get(name)
+    }
+
+    /// Parses schemas from the value stored under a contract's `__events_schema` named key.
+    pub fn from_cl_value(cl_value: &CLValue) -> Result<Self, CesError> {
+        Self::from_raw_bytes(cl_value.inner_bytes())
+    }
+
+    /// Parses schemas from their raw bytesrepr encoding.
+    pub fn from_raw_bytes(bytes: &[u8]) -> Result<Self, CesError> {
+        let (schemas, remainder) = Self::from_bytes(bytes)?;
+        if !remainder.is_empty() {
+            return Err(bytesrepr::Error::LeftOverBytes.into());
+        }
+        Ok(schemas)
+    }
+}
+
+impl FromBytes for Schemas {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (schemas, remainder) = BTreeMap::<String, Schema>::from_bytes(bytes)?;
+        Ok((Schemas(schemas), remainder))
+    }
+}
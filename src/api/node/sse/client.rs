@@ -1,8 +1,13 @@
 use super::{
+    ces::{CesError, ContractEvent, Decoder},
     error::ClientError,
+    fault::{Fault, FaultAlert, FaultError, FaultMonitor},
+    metrics,
+    step::{EraEnded, StepError},
     types::{CoreCommand, EventType},
     ClientCore, SseData,
 };
+use crate::utils::trace::trace_event;
 use std::time::Duration;
 use tokio::sync::{mpsc, oneshot};
 
@@ -17,6 +22,7 @@ impl Client {
         let (tx, rx) = mpsc::channel(32);
         let _handle = tokio::spawn(async move {
             if let Err(e) = run_client_core(rx, client_core).await {
+                trace_event!(error, error = %e, "unrecoverable client error");
                 panic!("Unrecoverable client error: {}", e);
             }
         });
@@ -53,6 +59,75 @@ impl Client {
         rx.await.map_err(|err| ClientError::CommandRecvError(err))
     }
 
+    /// Registers a handler invoked with each CES event decoded from `TransactionProcessed` events.
+    pub async fn on_contract_event<F>(
+        &mut self,
+        decoder: Decoder,
+        handler: F,
+    ) -> Result<u64, ClientError>
+    where
+        F: Fn(Result<ContractEvent, CesError>) + 'static + Send + Sync,
+    {
+        self.on_event(
+            EventType::TransactionProcessed,
+            move |event: SseData| match decoder.decode_event(&event) {
+                Ok(contract_events) => contract_events.into_iter().map(Ok).for_each(&handler),
+                Err(err) => handler(Err(err)),
+            },
+        )
+        .await
+    }
+
+    /// Registers a handler invoked with a summary of each era's end, as derived from `Step` events.
+    pub async fn on_era_ended<F>(&mut self, handler: F) -> Result<u64, ClientError>
+    where
+        F: Fn(Result<EraEnded, StepError>) + 'static + Send + Sync,
+    {
+        self.on_event(EventType::Step, move |event: SseData| {
+            handler(EraEnded::from_event(&event))
+        })
+        .await
+    }
+
+    /// Registers a handler invoked with an alert whenever a validator watched by the monitor is
+    /// at fault.  Faults of unwatched validators are counted by the monitor but not raised.
+    pub async fn on_validator_fault<F>(
+        &mut self,
+        monitor: FaultMonitor,
+        handler: F,
+    ) -> Result<u64, ClientError>
+    where
+        F: Fn(Result<FaultAlert, FaultError>) + 'static + Send + Sync,
+    {
+        self.on_event(
+            EventType::Fault,
+            move |event: SseData| match Fault::from_event(&event) {
+                Ok(fault) => {
+                    if let Some(alert) = monitor.record(fault) {
+                        handler(Ok(alert))
+                    }
+                }
+                Err(err) => handler(Err(err)),
+            },
+        )
+        .await
+    }
+
+    /// Channel based equivalent of `on_validator_fault`.  Alerts are dropped if the channel is full.
+    pub async fn validator_fault_channel(
+        &mut self,
+        monitor: FaultMonitor,
+        buffer: usize,
+    ) -> Result<(u64, mpsc::Receiver<Result<FaultAlert, FaultError>>), ClientError> {
+        let (tx, rx) = mpsc::channel(buffer);
+        let handler_id = self
+            .on_validator_fault(monitor, move |alert| {
+                let _ = tx.try_send(alert);
+            })
+            .await?;
+        Ok((handler_id, rx))
+    }
+
     pub async fn wait_for_event<F>(
         &mut self,
         event_type: EventType,
@@ -92,7 +167,11 @@ impl Client {
         match result {
             Some(event_info) => Ok(Some(event_info)),
             None => {
-                eprintln!("Timed out or stream exhausted while waiting for event");
+                trace_event!(
+                    warn,
+                    event_type = event_type.as_str(),
+                    "timed out or stream exhausted while waiting for event"
+                );
                 Ok(None)
             }
         }
@@ -109,6 +188,10 @@ impl Client {
 }
 
 /// Handles incoming commands and delegates tasks to ClientCore.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "sse_client_core", skip_all, fields(endpoint = %client_core.url()), err)
+)]
 async fn run_client_core(
     mut rx: mpsc::Receiver<CoreCommand>,
     mut client_core: ClientCore,
@@ -117,7 +200,8 @@ async fn run_client_core(
         if !client_core.is_connected() {
             // Not connected yet, so only process Connect commands.
             if let Some(command) = rx.recv().await {
-                client_core.handle_command(command).await?
+                client_core.handle_command(command).await?;
+                metrics::record_command(client_core.is_connected());
             }
         } else {
             tokio::select! {
@@ -126,7 +210,8 @@ async fn run_client_core(
                 },
                 Some(command) = rx.recv() => {
                       client_core.handle_command(command)
-                            .await?
+                            .await?;
+                      metrics::record_command(client_core.is_connected());
                 },
             }
         }
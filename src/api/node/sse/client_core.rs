@@ -1,11 +1,14 @@
 use super::{
     error::ClientError,
+    metrics,
     types::{BoxedEventStream, CoreCommand, EventType, Handler},
     SseData,
 };
+use crate::utils::trace::trace_event;
+use casper_types::ProtocolVersion;
 use eventsource_stream::{Event, Eventsource};
 use futures::stream::TryStreamExt;
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Instant};
 
 pub struct ClientCore {
     url: String,
@@ -14,6 +17,7 @@ pub struct ClientCore {
     event_handlers: HashMap<EventType, HashMap<u64, Box<dyn Fn(SseData) + Send + Sync + 'static>>>,
     id_types: HashMap<u64, EventType>,
     is_connected: bool,
+    api_version: Option<ProtocolVersion>,
 }
 
 impl ClientCore {
@@ -25,11 +29,17 @@ impl ClientCore {
             event_handlers: HashMap::new(),
             id_types: HashMap::new(),
             is_connected: false,
+            api_version: None,
         }
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "sse_connect", skip_all, fields(endpoint = %self.url), err)
+    )]
     pub async fn connect(&mut self) -> Result<(), ClientError> {
         // Connect to SSE endpoint.
+        trace_event!(debug, "connecting to SSE endpoint");
         let client = reqwest::Client::new();
         let response = client.get(&self.url).send().await?;
 
@@ -42,15 +52,18 @@ impl ClientCore {
             .await?
             .ok_or(ClientError::StreamExhausted)?;
         let handshake_data: SseData = serde_json::from_str(&handshake_event.data)?;
-        let _api_version = match handshake_data {
+        let api_version = match handshake_data {
             SseData::ApiVersion(v) => Ok(v),
             _ => Err(ClientError::InvalidHandshake),
         }?;
+        trace_event!(info, %api_version, "SSE handshake completed");
 
         // Wrap stream with box and store it.
         let boxed_event_stream = Box::pin(event_stream);
         self.event_stream = Some(boxed_event_stream);
         self.is_connected = true;
+        metrics::record_connect(self.api_version.is_some());
+        self.api_version = Some(api_version);
 
         Ok(())
     }
@@ -73,19 +86,56 @@ impl ClientCore {
         self.is_connected
     }
 
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns API version announced by the node during the most recent handshake.
+    pub fn api_version(&self) -> Option<ProtocolVersion> {
+        self.api_version
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "sse_event", skip_all, fields(event_id = %event.id))
+    )]
     pub fn handle_event(&mut self, event: Event) -> Result<(), ClientError> {
-        let data: SseData = serde_json::from_str(&event.data)?;
+        metrics::record_event_received(event.data.len());
+        let data: SseData = match serde_json::from_str(&event.data) {
+            Ok(data) => data,
+            Err(err) => {
+                metrics::record_decode_error();
+                trace_event!(warn, error = %err, "undecodable event");
+                return Err(err.into());
+            }
+        };
+        metrics::record_event_decoded(data.event_type());
+        trace_event!(
+            debug,
+            event_type = data.event_type().as_str(),
+            transaction_hash = data.transaction_hash().map(tracing::field::display),
+            "dispatching event"
+        );
 
         match data {
-            SseData::ApiVersion(_) => return Err(ClientError::UnexpectedHandshake), // Should only happen once at connection
-            SseData::Shutdown => return Err(ClientError::NodeShutdown),
+            SseData::ApiVersion(_) => {
+                trace_event!(error, "unexpected handshake event");
+                return Err(ClientError::UnexpectedHandshake); // Should only happen once at connection
+            }
+            SseData::Shutdown => {
+                trace_event!(warn, "node shutdown");
+                return Err(ClientError::NodeShutdown);
+            }
 
             // For each type, find and invoke registered handlers
             event => {
-                if let Some(handlers) = self.event_handlers.get_mut(&event.event_type()) {
+                let event_type = event.event_type();
+                if let Some(handlers) = self.event_handlers.get_mut(&event_type) {
+                    let started_at = Instant::now();
                     for handler in handlers.values() {
                         handler(event.clone()); // Invoke each handler for the event
                     }
+                    metrics::record_handler_duration(event_type, started_at.elapsed());
                 }
             }
         }
@@ -96,8 +146,16 @@ impl ClientCore {
         if let Some(stream) = self.event_stream.as_mut() {
             match stream.try_next().await {
                 Ok(Some(event)) => Ok(Some(event)),
-                Ok(None) => Err(ClientError::StreamExhausted),
-                Err(err) => Err(ClientError::EventStreamError(err)),
+                Ok(None) => {
+                    metrics::record_stream_error();
+                    trace_event!(warn, endpoint = %self.url, "SSE stream exhausted");
+                    Err(ClientError::StreamExhausted)
+                }
+                Err(err) => {
+                    metrics::record_stream_error();
+                    trace_event!(warn, endpoint = %self.url, error = %err, "SSE stream error");
+                    Err(ClientError::EventStreamError(err))
+                }
             }
         } else {
             Err(ClientError::NoEventStreamAvailable)
@@ -113,6 +171,10 @@ impl ClientCore {
         handler_id
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "sse_command", skip_all, fields(command = command.name()), err)
+    )]
     pub async fn handle_command(&mut self, command: CoreCommand) -> Result<(), ClientError> {
         match command {
             CoreCommand::AddOnEventHandler(event_type, callback, completion_ack) => {
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FaultError {
+    #[error("Invalid Fault payload: {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+
+    #[error("Event is not a Fault event")]
+    UnexpectedEventType,
+}
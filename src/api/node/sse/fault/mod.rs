@@ -0,0 +1,10 @@
+//! Validator monitoring over `Fault` events.
+//!
+//! A `Fault` event is emitted when a validator equivocates.  This module maps such events to a
+//! typed record and raises alerts when a validator within a watchlist is at fault.
+
+pub mod error;
+pub mod monitor;
+
+pub use error::FaultError;
+pub use monitor::{Fault, FaultAlert, FaultMonitor, DEFAULT_ERA_WINDOW};
@@ -0,0 +1,139 @@
+use super::error::FaultError;
+use crate::api::node::sse::SseData;
+use casper_types::{EraId, PublicKey, Timestamp};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+/// Payload of a `Fault` event.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Fault {
+    pub era_id: EraId,
+    pub public_key: PublicKey,
+    pub timestamp: Timestamp,
+}
+
+impl Fault {
+    pub fn from_event(event: &SseData) -> Result<Self, FaultError> {
+        match event {
+            SseData::Fault(payload) => Ok(Self::deserialize(payload)?),
+            _ => Err(FaultError::UnexpectedEventType),
+        }
+    }
+}
+
+/// Default number of most recent eras within which faults are tracked.
+pub const DEFAULT_ERA_WINDOW: u64 = 10;
+
+/// Alert raised when a watched validator is at fault.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FaultAlert {
+    pub fault: Fault,
+    /// Number of faults observed within the fault's era, across all validators.
+    pub faults_in_era: usize,
+}
+
+/// Tracks faults across eras and raises alerts for a watchlist of validators.
+///
+/// A monitor is cheaply cloneable; clones share the same watchlist & fault counts.
+///
+/// Faults are tracked per (era, validator) so that an event redelivered after a reconnect is
+/// neither counted nor raised twice. Only the most recent `era_window` eras are retained: older
+/// eras are pruned as newer faults arrive, and faults within pruned eras are ignored.
+#[derive(Clone, Debug)]
+pub struct FaultMonitor {
+    era_window: u64,
+    watchlist: Arc<Mutex<HashSet<PublicKey>>>,
+    faulty_per_era: Arc<Mutex<BTreeMap<EraId, HashSet<PublicKey>>>>,
+}
+
+impl Default for FaultMonitor {
+    fn default() -> Self {
+        FaultMonitor::with_era_window(DEFAULT_ERA_WINDOW)
+    }
+}
+
+impl FaultMonitor {
+    pub fn new() -> Self {
+        FaultMonitor::default()
+    }
+
+    /// Creates a monitor tracking faults within the given number of most recent eras.
+    pub fn with_era_window(era_window: u64) -> Self {
+        FaultMonitor {
+            era_window: era_window.max(1),
+            watchlist: Default::default(),
+            faulty_per_era: Default::default(),
+        }
+    }
+
+    pub fn with_validators(validators: impl IntoIterator<Item = PublicKey>) -> Self {
+        let monitor = FaultMonitor::new();
+        monitor.watchlist.lock().unwrap().extend(validators);
+        monitor
+    }
+
+    /// Adds a validator to the watchlist, returning false if it was already watched.
+    pub fn watch(&self, public_key: PublicKey) -> bool {
+        self.watchlist.lock().unwrap().insert(public_key)
+    }
+
+    /// Removes a validator from the watchlist, returning false if it was not watched.
+    pub fn unwatch(&self, public_key: &PublicKey) -> bool {
+        self.watchlist.lock().unwrap().remove(public_key)
+    }
+
+    pub fn is_watched(&self, public_key: &PublicKey) -> bool {
+        self.watchlist.lock().unwrap().contains(public_key)
+    }
+
+    /// Records a fault, returning an alert if the faulty validator is watched.
+    ///
+    /// No alert is returned for a fault already recorded or for a fault within a pruned era.
+    pub fn record(&self, fault: Fault) -> Option<FaultAlert> {
+        let faults_in_era = {
+            let mut faulty_per_era = self.faulty_per_era.lock().unwrap();
+            let latest_era_id = faulty_per_era
+                .last_key_value()
+                .map_or(fault.era_id, |(era_id, _)| fault.era_id.max(*era_id));
+            let oldest_era_id =
+                EraId::new(latest_era_id.value().saturating_sub(self.era_window - 1));
+            if fault.era_id < oldest_era_id {
+                return None;
+            }
+            *faulty_per_era = faulty_per_era.split_off(&oldest_era_id);
+
+            let faulty = faulty_per_era.entry(fault.era_id).or_default();
+            if !faulty.insert(fault.public_key.clone()) {
+                return None;
+            }
+            faulty.len()
+        };
+
+        self.is_watched(&fault.public_key).then_some(FaultAlert {
+            fault,
+            faults_in_era,
+        })
+    }
+
+    /// Returns number of faults observed within an era.
+    pub fn faults_in_era(&self, era_id: EraId) -> usize {
+        self.faulty_per_era
+            .lock()
+            .unwrap()
+            .get(&era_id)
+            .map_or(0, HashSet::len)
+    }
+
+    /// Returns number of faults observed per era, over the retained eras.
+    pub fn faults_per_era(&self) -> BTreeMap<EraId, usize> {
+        self.faulty_per_era
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(era_id, faulty)| (*era_id, faulty.len()))
+            .collect()
+    }
+}
@@ -0,0 +1,99 @@
+//! SSE client instrumentation.
+//!
+//! When the `metrics` feature is enabled, the client emits the following metrics via the
+//! [`metrics`](https://docs.rs/metrics) facade.  Applications export them by installing a
+//! recorder, e.g. `metrics-exporter-prometheus`.  When the feature is disabled, recording is a no-op.
+
+use super::types::EventType;
+use std::time::Duration;
+
+/// Counter: events received, whether or not they decode.
+pub const EVENTS_RECEIVED: &str = "casper_sse_events_received_total";
+/// Counter: event payload bytes received, whether or not they decode.
+pub const BYTES_RECEIVED: &str = "casper_sse_bytes_received_total";
+/// Counter: events decoded, labelled by `event_type`.
+pub const EVENTS_DECODED: &str = "casper_sse_events_decoded_total";
+/// Counter: events whose payload failed to decode.
+pub const DECODE_ERRORS: &str = "casper_sse_decode_errors_total";
+/// Counter: successful connections to the SSE endpoint.
+pub const CONNECTS: &str = "casper_sse_connects_total";
+/// Counter: successful connections following a previous connection.
+pub const RECONNECTS: &str = "casper_sse_reconnects_total";
+/// Counter: errors whilst reading from the event stream.
+pub const STREAM_ERRORS: &str = "casper_sse_stream_errors_total";
+/// Counter: commands processed by the client core.
+pub const COMMANDS: &str = "casper_sse_commands_total";
+/// Histogram: time taken to invoke all handlers of an event, labelled by `event_type`.
+pub const HANDLER_DURATION_SECONDS: &str = "casper_sse_handler_duration_seconds";
+/// Gauge: unix timestamp (seconds) of last event received - subtract from now to detect stalls.
+pub const LAST_EVENT_TIMESTAMP_SECONDS: &str = "casper_sse_last_event_timestamp_seconds";
+/// Gauge: 1 if the client core is connected, otherwise 0.
+pub const CONNECTED: &str = "casper_sse_connected";
+
+#[cfg(feature = "metrics")]
+mod recorder {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    const LABEL_EVENT_TYPE: &str = "event_type";
+
+    pub fn record_event_received(bytes: usize) {
+        ::metrics::counter!(EVENTS_RECEIVED).increment(1);
+        ::metrics::counter!(BYTES_RECEIVED).increment(bytes as u64);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        ::metrics::gauge!(LAST_EVENT_TIMESTAMP_SECONDS).set(now.as_secs_f64());
+    }
+
+    pub fn record_event_decoded(event_type: EventType) {
+        let label = event_type.as_str();
+        ::metrics::counter!(EVENTS_DECODED, LABEL_EVENT_TYPE => label).increment(1);
+    }
+
+    pub fn record_decode_error() {
+        ::metrics::counter!(DECODE_ERRORS).increment(1);
+    }
+
+    pub fn record_handler_duration(event_type: EventType, duration: Duration) {
+        let label = event_type.as_str();
+        ::metrics::histogram!(HANDLER_DURATION_SECONDS, LABEL_EVENT_TYPE => label).record(duration);
+    }
+
+    pub fn record_connect(is_reconnect: bool) {
+        ::metrics::counter!(CONNECTS).increment(1);
+        if is_reconnect {
+            ::metrics::counter!(RECONNECTS).increment(1);
+        }
+    }
+
+    pub fn record_stream_error() {
+        ::metrics::counter!(STREAM_ERRORS).increment(1);
+    }
+
+    pub fn record_command(is_connected: bool) {
+        ::metrics::counter!(COMMANDS).increment(1);
+        ::metrics::gauge!(CONNECTED).set(if is_connected { 1.0 } else { 0.0 });
+    }
+}
+
+#[cfg(not(feature = "metrics"))]
+mod recorder {
+    use super::*;
+
+    pub fn record_event_received(_bytes: usize) {}
+
+    pub fn record_event_decoded(_event_type: EventType) {}
+
+    pub fn record_decode_error() {}
+
+    pub fn record_handler_duration(_event_type: EventType, _duration: Duration) {}
+
+    pub fn record_connect(_is_reconnect: bool) {}
+
+    pub fn record_stream_error() {}
+
+    pub fn record_command(_is_connected: bool) {}
+}
+
+pub(crate) use recorder::*;
@@ -1,5 +1,9 @@
+pub mod ces;
 pub mod client_core;
 pub mod error;
+pub mod fault;
+pub mod metrics;
+pub mod step;
 pub mod types;
 pub use client_core::ClientCore;
 pub use types::SseData;
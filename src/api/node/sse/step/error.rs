@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StepError {
+    #[error("Invalid Step payload: {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+
+    #[error("Event is not a Step event")]
+    UnexpectedEventType,
+}
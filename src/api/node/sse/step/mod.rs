@@ -0,0 +1,11 @@
+//! Interpretation of `Step` events.
+//!
+//! A `Step` event is emitted at the end of each era and carries the execution effects of the
+//! auction's end of era processing.  This module decodes those effects into an era summary
+//! suitable for validator monitoring.
+
+pub mod error;
+pub mod summary;
+
+pub use error::StepError;
+pub use summary::{AuctionChange, EraEnded, StepPayload};
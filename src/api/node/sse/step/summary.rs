@@ -0,0 +1,134 @@
+use super::error::StepError;
+use crate::api::node::sse::SseData;
+use casper_types::{
+    execution::{Effects, TransformKindV2},
+    system::auction::{BidAddr, BidKind, SeigniorageAllocation},
+    EraId, Key, PublicKey, StoredValue, U512,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Payload of a `Step` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct StepPayload {
+    pub era_id: EraId,
+    pub execution_effects: Effects,
+}
+
+impl StepPayload {
+    pub fn from_event(event: &SseData) -> Result<Self, StepError> {
+        match event {
+            SseData::Step(payload) => Ok(Self::deserialize(payload)?),
+            _ => Err(StepError::UnexpectedEventType),
+        }
+    }
+}
+
+/// A change to the auction's bid set applied at era end.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuctionChange {
+    /// A validator, delegator, unbond or other bid record was written.
+    BidWritten {
+        bid_addr: BidAddr,
+        bid: Box<BidKind>,
+    },
+    /// A bid record was removed from global state.
+    BidPruned { bid_addr: BidAddr },
+}
+
+impl AuctionChange {
+    pub fn bid_addr(&self) -> &BidAddr {
+        match self {
+            AuctionChange::BidWritten { bid_addr, .. } | AuctionChange::BidPruned { bid_addr } => {
+                bid_addr
+            }
+        }
+    }
+}
+
+/// Summary of an era's end, derived from a `Step` event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EraEnded {
+    /// Identifier of era that ended.
+    pub era_id: EraId,
+    /// Rewards allocated to validators & delegators for the era.
+    pub seigniorage_allocations: Vec<SeigniorageAllocation>,
+    /// Changes to the auction's bid set.
+    pub auction_changes: Vec<AuctionChange>,
+}
+
+impl EraEnded {
+    pub fn from_event(event: &SseData) -> Result<Self, StepError> {
+        Ok(Self::from(StepPayload::from_event(event)?))
+    }
+
+    /// Returns sum of all seigniorage allocations.
+    pub fn total_rewards(&self) -> U512 {
+        self.seigniorage_allocations
+            .iter()
+            .fold(U512::zero(), |acc, allocation| acc + *allocation.amount())
+    }
+
+    /// Returns rewards per validator, inclusive of rewards allocated to its delegators.
+    pub fn validator_rewards(&self) -> BTreeMap<PublicKey, U512> {
+        let mut rewards = BTreeMap::new();
+        for allocation in &self.seigniorage_allocations {
+            let (validator_public_key, amount) = match allocation {
+                SeigniorageAllocation::Validator {
+                    validator_public_key,
+                    amount,
+                }
+                | SeigniorageAllocation::Delegator {
+                    validator_public_key,
+                    amount,
+                    ..
+                }
+                | SeigniorageAllocation::DelegatorKind {
+                    validator_public_key,
+                    amount,
+                    ..
+                } => (validator_public_key, amount),
+            };
+            *rewards
+                .entry(validator_public_key.clone())
+                .or_insert_with(U512::zero) += *amount;
+        }
+        rewards
+    }
+}
+
+impl From<StepPayload> for EraEnded {
+    fn from(payload: StepPayload) -> Self {
+        let mut seigniorage_allocations = Vec::new();
+        let mut auction_changes = Vec::new();
+        for transform in payload.execution_effects.transforms() {
+            match (transform.key(), transform.kind()) {
+                (
+                    Key::EraSummary | Key::EraInfo(_),
+                    TransformKindV2::Write(StoredValue::EraInfo(era_info)),
+                ) => {
+                    seigniorage_allocations
+                        .extend(era_info.seigniorage_allocations().iter().cloned());
+                }
+                (Key::BidAddr(bid_addr), TransformKindV2::Write(StoredValue::BidKind(bid))) => {
+                    auction_changes.push(AuctionChange::BidWritten {
+                        bid_addr: *bid_addr,
+                        bid: Box::new(bid.clone()),
+                    });
+                }
+                (Key::BidAddr(bid_addr), TransformKindV2::Prune(_)) => {
+                    auction_changes.push(AuctionChange::BidPruned {
+                        bid_addr: *bid_addr,
+                    });
+                }
+                _ => (),
+            }
+        }
+
+        EraEnded {
+            era_id: payload.era_id,
+            seigniorage_allocations,
+            auction_changes,
+        }
+    }
+}
@@ -19,6 +19,23 @@ pub enum EventType {
     Shutdown,
 }
 
+impl EventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventType::ApiVersion => "ApiVersion",
+            EventType::SidecarVersion => "SidecarVersion",
+            EventType::BlockAdded => "BlockAdded",
+            EventType::TransactionAccepted => "TransactionAccepted",
+            EventType::TransactionProcessed => "TransactionProcessed",
+            EventType::TransactionExpired => "TransactionExpired",
+            EventType::Fault => "Fault",
+            EventType::FinalitySignature => "FinalitySignature",
+            EventType::Step => "Step",
+            EventType::Shutdown => "Shutdown",
+        }
+    }
+}
+
 /// Casper does not expose SSE types directly, so we have to reimplement them.
 /// Source: https://github.com/casper-network/casper-node/blob/8a9a864212b7c20fc17e1d0106b02c813ffded9d/node/src/components/event_stream_server/sse_server.rs#L56.
 /// TODO: Add full deserialization details.
@@ -51,6 +68,16 @@ impl SseData {
             SseData::Shutdown => EventType::Shutdown,
         }
     }
+
+    /// Returns hash of transaction to which a transaction event pertains.
+    pub fn transaction_hash(&self) -> Option<&serde_json::Value> {
+        match self {
+            SseData::TransactionAccepted(payload)
+            | SseData::TransactionProcessed(payload)
+            | SseData::TransactionExpired(payload) => payload.get("transaction_hash"),
+            _ => None,
+        }
+    }
 }
 
 pub enum CoreCommand {
@@ -59,5 +86,15 @@ pub enum CoreCommand {
     RemoveEventHandler(u64, oneshot::Sender<bool>),
 }
 
+impl CoreCommand {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CoreCommand::Connect(_) => "Connect",
+            CoreCommand::AddOnEventHandler(..) => "AddOnEventHandler",
+            CoreCommand::RemoveEventHandler(..) => "RemoveEventHandler",
+        }
+    }
+}
+
 pub type Handler = dyn Fn(SseData) + 'static + Send + Sync;
 pub type BoxedEventStream = BoxStream<'static, Result<Event, EventStreamError<reqwest::Error>>>;
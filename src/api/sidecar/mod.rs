@@ -1,2 +1,2 @@
-pub(super) mod rpc;
+pub mod rpc;
 pub(super) mod rpc_speculative;
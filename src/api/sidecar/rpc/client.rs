@@ -0,0 +1,346 @@
+use casper_types::{
+    BlockIdentifier, ChainspecRawBytes, DeployHash, Digest, EraId, Peers, PublicKey,
+    TransactionHash, ValidatorChange,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use super::{
+    error::{RpcError, ServerError},
+    types::{
+        BlockTransfers, BlockWithSignatures, DeployWithExecutionInfo, EraIdentifier, EraSummary,
+        NodeStatus, Reward, TransactionWithExecutionInfo,
+    },
+};
+
+/// Version of the JSON-RPC protocol spoken by the sidecar.
+const JSONRPC_VERSION: &str = "2.0";
+
+/// Client for a sidecar's main JSON-RPC server, e.g. `http://localhost:7777/rpc`.
+pub struct Client {
+    http: reqwest::Client,
+    url: String,
+    next_id: AtomicU64,
+}
+
+impl Client {
+    pub fn new(url: &str) -> Self {
+        Self::with_http_client(reqwest::Client::new(), url)
+    }
+
+    /// Constructs a client issuing requests via an HTTP client, e.g. one configured with a
+    /// timeout.
+    pub fn with_http_client(http: reqwest::Client, url: &str) -> Self {
+        Client {
+            http,
+            url: url.to_string(),
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns status of the node.
+    pub async fn get_status(&self) -> Result<NodeStatus, RpcError> {
+        self.call("info_get_status", None::<()>).await
+    }
+
+    /// Returns peers to which the node is connected.
+    pub async fn get_peers(&self) -> Result<Peers, RpcError> {
+        #[derive(Deserialize)]
+        struct GetPeersResult {
+            peers: Peers,
+        }
+
+        let result: GetPeersResult = self.call("info_get_peers", None::<()>).await?;
+        Ok(result.peers)
+    }
+
+    /// Returns raw bytes of the chainspec, genesis accounts & global state files.
+    pub async fn get_chainspec(&self) -> Result<ChainspecRawBytes, RpcError> {
+        #[derive(Deserialize)]
+        struct GetChainspecResult {
+            chainspec_bytes: ChainspecRawBytes,
+        }
+
+        let result: GetChainspecResult = self.call("info_get_chainspec", None::<()>).await?;
+        Ok(result.chainspec_bytes)
+    }
+
+    /// Returns changes to each validator's status, by era.
+    pub async fn get_validator_changes(
+        &self,
+    ) -> Result<BTreeMap<PublicKey, Vec<(EraId, ValidatorChange)>>, RpcError> {
+        #[derive(Deserialize)]
+        struct StatusChange {
+            era_id: EraId,
+            validator_change: ValidatorChange,
+        }
+        #[derive(Deserialize)]
+        struct ValidatorChanges {
+            public_key: PublicKey,
+            status_changes: Vec<StatusChange>,
+        }
+        #[derive(Deserialize)]
+        struct GetValidatorChangesResult {
+            changes: Vec<ValidatorChanges>,
+        }
+
+        let result: GetValidatorChangesResult =
+            self.call("info_get_validator_changes", None::<()>).await?;
+        Ok(result
+            .changes
+            .into_iter()
+            .map(|changes| {
+                let status_changes = changes
+                    .status_changes
+                    .into_iter()
+                    .map(|change| (change.era_id, change.validator_change))
+                    .collect();
+                (changes.public_key, status_changes)
+            })
+            .collect())
+    }
+
+    /// Returns reward of a validator, or of one of its delegators, in identified era, or in the
+    /// latest era if no identifier is specified.
+    pub async fn get_reward(
+        &self,
+        era_identifier: Option<EraIdentifier>,
+        validator: PublicKey,
+        delegator: Option<PublicKey>,
+    ) -> Result<Reward, RpcError> {
+        #[derive(Serialize)]
+        struct GetRewardParams {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            era_identifier: Option<EraIdentifier>,
+            validator: PublicKey,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            delegator: Option<PublicKey>,
+        }
+
+        let params = GetRewardParams {
+            era_identifier,
+            validator,
+            delegator,
+        };
+        self.call("info_get_reward", Some(params)).await
+    }
+
+    /// Returns a transaction (V1 or legacy deploy) along with its execution info, if executed.
+    /// If `finalized_approvals` is set, the approvals with which the transaction was finalized
+    /// are returned in place of those with which it was received.
+    pub async fn get_transaction(
+        &self,
+        transaction_hash: TransactionHash,
+        finalized_approvals: bool,
+    ) -> Result<TransactionWithExecutionInfo, RpcError> {
+        #[derive(Serialize)]
+        struct GetTransactionParams {
+            transaction_hash: TransactionHash,
+            finalized_approvals: bool,
+        }
+
+        let params = GetTransactionParams {
+            transaction_hash,
+            finalized_approvals,
+        };
+        self.call("info_get_transaction", Some(params)).await
+    }
+
+    /// Returns a legacy deploy along with its execution info, if executed.
+    pub async fn get_deploy(
+        &self,
+        deploy_hash: DeployHash,
+        finalized_approvals: bool,
+    ) -> Result<DeployWithExecutionInfo, RpcError> {
+        #[derive(Serialize)]
+        struct GetDeployParams {
+            deploy_hash: DeployHash,
+            finalized_approvals: bool,
+        }
+
+        let params = GetDeployParams {
+            deploy_hash,
+            finalized_approvals,
+        };
+        self.call("info_get_deploy", Some(params)).await
+    }
+
+    /// Returns identified block, or the latest block if no identifier is specified, along with
+    /// its signatures.
+    pub async fn get_block(
+        &self,
+        block_id: Option<BlockIdentifier>,
+    ) -> Result<Option<BlockWithSignatures>, RpcError> {
+        #[derive(Deserialize)]
+        struct GetBlockResult {
+            block_with_signatures: Option<BlockWithSignatures>,
+        }
+
+        let result: GetBlockResult = self
+            .call("chain_get_block", block_id.map(BlockIdentifierParams::new))
+            .await?;
+        Ok(result.block_with_signatures)
+    }
+
+    /// Returns transfers executed within identified block, or within the latest block if no
+    /// identifier is specified.
+    pub async fn get_block_transfers(
+        &self,
+        block_id: Option<BlockIdentifier>,
+    ) -> Result<BlockTransfers, RpcError> {
+        self.call(
+            "chain_get_block_transfers",
+            block_id.map(BlockIdentifierParams::new),
+        )
+        .await
+    }
+
+    /// Returns state root hash of identified block, or of the latest block if no identifier is
+    /// specified.
+    pub async fn get_state_root_hash(
+        &self,
+        block_id: Option<BlockIdentifier>,
+    ) -> Result<Option<Digest>, RpcError> {
+        #[derive(Deserialize)]
+        struct GetStateRootHashResult {
+            state_root_hash: Option<Digest>,
+        }
+
+        let result: GetStateRootHashResult = self
+            .call(
+                "chain_get_state_root_hash",
+                block_id.map(BlockIdentifierParams::new),
+            )
+            .await?;
+        Ok(result.state_root_hash)
+    }
+
+    /// Returns summary of the era of identified block, or of the latest block if no identifier
+    /// is specified.
+    pub async fn get_era_summary(
+        &self,
+        block_id: Option<BlockIdentifier>,
+    ) -> Result<EraSummary, RpcError> {
+        #[derive(Deserialize)]
+        struct GetEraSummaryResult {
+            era_summary: EraSummary,
+        }
+
+        let result: GetEraSummaryResult = self
+            .call(
+                "chain_get_era_summary",
+                block_id.map(BlockIdentifierParams::new),
+            )
+            .await?;
+        Ok(result.era_summary)
+    }
+
+    /// Returns summary of the era ended by identified block, or by the latest block if no
+    /// identifier is specified, or `None` if the block is not a switch block.
+    pub async fn get_era_info_by_switch_block(
+        &self,
+        block_id: Option<BlockIdentifier>,
+    ) -> Result<Option<EraSummary>, RpcError> {
+        #[derive(Deserialize)]
+        struct GetEraInfoResult {
+            era_summary: Option<EraSummary>,
+        }
+
+        let result: GetEraInfoResult = self
+            .call(
+                "chain_get_era_info_by_switch_block",
+                block_id.map(BlockIdentifierParams::new),
+            )
+            .await?;
+        Ok(result.era_summary)
+    }
+
+    /// Invokes a JSON-RPC method, omitting params if `None`, and decodes its result.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "rpc_call", skip_all, fields(endpoint = %self.url, method = %method), err)
+    )]
+    pub async fn call<P, R>(&self, method: &str, params: Option<P>) -> Result<R, RpcError>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let request = Request {
+            jsonrpc: JSONRPC_VERSION,
+            id,
+            method,
+            params,
+        };
+        let bytes = self
+            .http
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+
+        let response: Response = serde_json::from_slice(&bytes)?;
+        if response.jsonrpc != JSONRPC_VERSION {
+            return Err(RpcError::InvalidResponse(format!(
+                "unsupported JSON-RPC version {}",
+                response.jsonrpc
+            )));
+        }
+        if let Some(error) = response.error {
+            return Err(RpcError::Server(error));
+        }
+        if response.id != id {
+            return Err(RpcError::IdMismatch {
+                expected: id,
+                got: response.id,
+            });
+        }
+        let result = response
+            .result
+            .ok_or_else(|| RpcError::InvalidResponse("neither result nor error".to_string()))?;
+        Ok(serde_json::from_value(result)?)
+    }
+}
+
+#[derive(Serialize)]
+struct Request<'a, P> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    params: Option<P>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Value,
+    #[serde(default)]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<ServerError>,
+}
+
+/// Params of methods taking an optional block identifier.
+#[derive(Serialize)]
+pub(super) struct BlockIdentifierParams {
+    block_identifier: BlockIdentifier,
+}
+
+impl BlockIdentifierParams {
+    pub(super) fn new(block_identifier: BlockIdentifier) -> Self {
+        BlockIdentifierParams { block_identifier }
+    }
+}
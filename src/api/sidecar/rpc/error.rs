@@ -0,0 +1,117 @@
+use l1_binary_port::ErrorCode;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt::{self, Display, Formatter};
+use thiserror::Error;
+
+use crate::api::node::binary::error::NodeError;
+
+/// Sidecar's error code for a deploy rejected by the node.
+const INVALID_DEPLOY: i64 = -32008;
+/// Sidecar's error code for a transaction rejected by the node.
+const INVALID_TRANSACTION: i64 = -32016;
+
+/// Error arising from interaction with a sidecar's JSON-RPC server.
+#[derive(Error, Debug)]
+pub enum RpcError {
+    /// Server could not be reached, or answered with an HTTP error status.
+    #[error("Transport error: {0}")]
+    Transport(#[from] reqwest::Error),
+
+    /// Request params could not be encoded, or the server's response decoded.
+    #[error("Serialization error: {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    /// Server's response is not a JSON-RPC 2.0 response to the request.
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+
+    /// Server's response is to a request other than that sent.
+    #[error("Response id mismatch: expected {expected}, got {got}")]
+    IdMismatch { expected: u64, got: Value },
+
+    /// Server understood but declined the request.
+    #[error("Server error: {0}")]
+    Server(ServerError),
+
+    /// Node rejected a submitted transaction or deploy.
+    #[error("Invalid transaction: {0}")]
+    InvalidTransaction(InvalidTransactionError),
+}
+
+/// Error object of a JSON-RPC 2.0 response.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct ServerError {
+    pub code: i64,
+    pub message: String,
+    #[serde(default)]
+    pub data: Option<Value>,
+}
+
+impl Display for ServerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (code {})", self.message, self.code)?;
+        if let Some(data) = &self.data {
+            write!(f, ": {data}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Rejection by the node of a submitted transaction or deploy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidTransactionError {
+    /// Node's reason for rejection, e.g. `ErrorCode::TransactionExpired`, if the error's data
+    /// describes one.
+    pub reason: Option<ErrorCode>,
+    pub error: ServerError,
+}
+
+impl InvalidTransactionError {
+    /// Maps a server error in response to a submission, returning it as is if not a rejection.
+    pub(super) fn try_from_server_error(error: ServerError) -> Result<Self, ServerError> {
+        if matches!(error.code, INVALID_DEPLOY | INVALID_TRANSACTION) {
+            let reason = rejection_reason(&error);
+            Ok(InvalidTransactionError { reason, error })
+        } else {
+            Err(error)
+        }
+    }
+}
+
+impl Display for InvalidTransactionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.reason {
+            Some(reason) => write!(f, "{reason}"),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+/// Returns the node error code, of those rejecting a transaction, which a rejection's data
+/// describes.
+///
+/// The sidecar reports the node's reason as data of the form `the transaction was invalid:
+/// <reason>`, or `the deploy was invalid: <reason>`, the reason being the description of the
+/// node's error code.
+fn rejection_reason(error: &ServerError) -> Option<ErrorCode> {
+    let prefix = match error.code {
+        INVALID_DEPLOY => "the deploy was invalid: ",
+        INVALID_TRANSACTION => "the transaction was invalid: ",
+        _ => return None,
+    };
+    let reason = error.data.as_ref()?.as_str()?.strip_prefix(prefix)?;
+
+    (0..)
+        .map_while(|error_code| ErrorCode::try_from(error_code).ok())
+        .filter(|error_code| {
+            matches!(
+                NodeError::from(*error_code),
+                NodeError::InvalidTransaction(_)
+                    | NodeError::TransactionNotExecutable(_)
+                    | NodeError::GasPriceTooHigh(_)
+                    | NodeError::WasmPreprocessing
+            )
+        })
+        .find(|error_code| error_code.to_string().eq_ignore_ascii_case(reason))
+}
@@ -0,0 +1,8 @@
+pub mod client;
+pub mod error;
+mod state;
+mod transaction;
+pub mod types;
+
+pub use client::Client;
+pub use error::RpcError;
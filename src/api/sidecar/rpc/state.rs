@@ -0,0 +1,226 @@
+use casper_types::{
+    bytesrepr::Bytes, BlockIdentifier, Digest, GlobalStateIdentifier, Key, URef, U512,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    client::{BlockIdentifierParams, Client},
+    error::RpcError,
+    types::{
+        AccountIdentifier, AccountWithProof, AuctionState, BalanceDetails, BalanceWithProof,
+        DictionaryIdentifier, DictionaryItem, EntityIdentifier, EntityWithProof, PurseIdentifier,
+        StoredValueWithProof,
+    },
+};
+
+/// Params of balance queries.
+#[derive(Serialize)]
+struct QueryBalanceParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state_identifier: Option<GlobalStateIdentifier>,
+    purse_identifier: PurseIdentifier,
+}
+
+impl Client {
+    /// Returns value stored under a key, or under a path of named keys from it, in identified
+    /// global state, or in that of the latest block if no identifier is specified.
+    pub async fn query_global_state(
+        &self,
+        state_identifier: Option<GlobalStateIdentifier>,
+        key: Key,
+        path: Vec<String>,
+    ) -> Result<StoredValueWithProof, RpcError> {
+        #[derive(Serialize)]
+        struct QueryGlobalStateParams {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            state_identifier: Option<GlobalStateIdentifier>,
+            key: Key,
+            path: Vec<String>,
+        }
+
+        let params = QueryGlobalStateParams {
+            state_identifier,
+            key,
+            path,
+        };
+        self.call("query_global_state", Some(params)).await
+    }
+
+    /// Returns total balance of a purse in identified global state, or in that of the latest
+    /// block if no identifier is specified.
+    pub async fn query_balance(
+        &self,
+        state_identifier: Option<GlobalStateIdentifier>,
+        purse_identifier: PurseIdentifier,
+    ) -> Result<U512, RpcError> {
+        #[derive(Deserialize)]
+        struct QueryBalanceResult {
+            balance: U512,
+        }
+
+        let params = QueryBalanceParams {
+            state_identifier,
+            purse_identifier,
+        };
+        let result: QueryBalanceResult = self.call("query_balance", Some(params)).await?;
+        Ok(result.balance)
+    }
+
+    /// Returns total & available balance of a purse, along with holds upon it, in identified
+    /// global state, or in that of the latest block if no identifier is specified.
+    pub async fn query_balance_details(
+        &self,
+        state_identifier: Option<GlobalStateIdentifier>,
+        purse_identifier: PurseIdentifier,
+    ) -> Result<BalanceDetails, RpcError> {
+        let params = QueryBalanceParams {
+            state_identifier,
+            purse_identifier,
+        };
+        self.call("query_balance_details", Some(params)).await
+    }
+
+    /// Returns value stored under a key, or under a path of named keys from it, in global state
+    /// under a state root hash.
+    pub async fn get_item(
+        &self,
+        state_root_hash: Digest,
+        key: Key,
+        path: Vec<String>,
+    ) -> Result<StoredValueWithProof, RpcError> {
+        #[derive(Serialize)]
+        struct GetItemParams {
+            state_root_hash: Digest,
+            key: Key,
+            path: Vec<String>,
+        }
+
+        let params = GetItemParams {
+            state_root_hash,
+            key,
+            path,
+        };
+        self.call("state_get_item", Some(params)).await
+    }
+
+    /// Returns balance of a purse in global state under a state root hash.
+    pub async fn get_balance(
+        &self,
+        state_root_hash: Digest,
+        purse_uref: URef,
+    ) -> Result<BalanceWithProof, RpcError> {
+        #[derive(Serialize)]
+        struct GetBalanceParams {
+            state_root_hash: Digest,
+            purse_uref: String,
+        }
+
+        let params = GetBalanceParams {
+            state_root_hash,
+            purse_uref: purse_uref.to_formatted_string(),
+        };
+        self.call("state_get_balance", Some(params)).await
+    }
+
+    /// Returns identified dictionary item in global state under a state root hash.
+    pub async fn get_dictionary_item(
+        &self,
+        state_root_hash: Digest,
+        dictionary_identifier: DictionaryIdentifier,
+    ) -> Result<DictionaryItem, RpcError> {
+        #[derive(Serialize)]
+        struct GetDictionaryItemParams {
+            state_root_hash: Digest,
+            dictionary_identifier: DictionaryIdentifier,
+        }
+
+        let params = GetDictionaryItemParams {
+            state_root_hash,
+            dictionary_identifier,
+        };
+        self.call("state_get_dictionary_item", Some(params)).await
+    }
+
+    /// Returns identified legacy account as at identified block, or the latest block if no
+    /// identifier is specified.
+    pub async fn get_account_info(
+        &self,
+        account_identifier: AccountIdentifier,
+        block_id: Option<BlockIdentifier>,
+    ) -> Result<AccountWithProof, RpcError> {
+        #[derive(Serialize)]
+        struct GetAccountInfoParams {
+            account_identifier: AccountIdentifier,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            block_identifier: Option<BlockIdentifier>,
+        }
+
+        let params = GetAccountInfoParams {
+            account_identifier,
+            block_identifier: block_id,
+        };
+        self.call("state_get_account_info", Some(params)).await
+    }
+
+    /// Returns identified addressable entity, or legacy account or contract, as at identified
+    /// block, or the latest block if no identifier is specified.  If `include_bytecode` is set,
+    /// the entity's byte code, or contract's wasm, is also returned.
+    pub async fn get_entity(
+        &self,
+        entity_identifier: EntityIdentifier,
+        block_id: Option<BlockIdentifier>,
+        include_bytecode: bool,
+    ) -> Result<EntityWithProof, RpcError> {
+        #[derive(Serialize)]
+        struct GetEntityParams {
+            entity_identifier: EntityIdentifier,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            block_identifier: Option<BlockIdentifier>,
+            include_bytecode: bool,
+        }
+
+        let params = GetEntityParams {
+            entity_identifier,
+            block_identifier: block_id,
+            include_bytecode,
+        };
+        self.call("state_get_entity", Some(params)).await
+    }
+
+    /// Returns state of the auction as at identified block, or the latest block if no identifier
+    /// is specified.
+    pub async fn get_auction_info(
+        &self,
+        block_id: Option<BlockIdentifier>,
+    ) -> Result<AuctionState, RpcError> {
+        #[derive(Deserialize)]
+        struct GetAuctionInfoResult {
+            auction_state: AuctionState,
+        }
+
+        let result: GetAuctionInfoResult = self
+            .call(
+                "state_get_auction_info",
+                block_id.map(BlockIdentifierParams::new),
+            )
+            .await?;
+        Ok(result.auction_state)
+    }
+
+    /// Returns serialized global state trie identified by its hash, unverified.
+    pub async fn get_trie(&self, trie_key: Digest) -> Result<Option<Bytes>, RpcError> {
+        #[derive(Serialize)]
+        struct GetTrieParams {
+            trie_key: Digest,
+        }
+        #[derive(Deserialize)]
+        struct GetTrieResult {
+            maybe_trie_bytes: Option<Bytes>,
+        }
+
+        let result: GetTrieResult = self
+            .call("state_get_trie", Some(GetTrieParams { trie_key }))
+            .await?;
+        Ok(result.maybe_trie_bytes)
+    }
+}
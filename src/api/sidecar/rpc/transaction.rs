@@ -0,0 +1,64 @@
+use casper_types::{Deploy, DeployHash, Transaction, TransactionHash};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    client::Client,
+    error::{InvalidTransactionError, RpcError},
+};
+
+impl Client {
+    /// Submits a transaction (V1 or legacy deploy) to the node for inclusion within a block,
+    /// returning its hash once accepted.
+    pub async fn put_transaction(
+        &self,
+        transaction: Transaction,
+    ) -> Result<TransactionHash, RpcError> {
+        #[derive(Serialize)]
+        struct PutTransactionParams {
+            transaction: Transaction,
+        }
+        #[derive(Deserialize)]
+        struct PutTransactionResult {
+            transaction_hash: TransactionHash,
+        }
+
+        let result: PutTransactionResult = self
+            .call(
+                "account_put_transaction",
+                Some(PutTransactionParams { transaction }),
+            )
+            .await
+            .map_err(map_rejection)?;
+        Ok(result.transaction_hash)
+    }
+
+    /// Submits a legacy deploy to the node for inclusion within a block, returning its hash once
+    /// accepted.
+    pub async fn put_deploy(&self, deploy: Deploy) -> Result<DeployHash, RpcError> {
+        #[derive(Serialize)]
+        struct PutDeployParams {
+            deploy: Deploy,
+        }
+        #[derive(Deserialize)]
+        struct PutDeployResult {
+            deploy_hash: DeployHash,
+        }
+
+        let result: PutDeployResult = self
+            .call("account_put_deploy", Some(PutDeployParams { deploy }))
+            .await
+            .map_err(map_rejection)?;
+        Ok(result.deploy_hash)
+    }
+}
+
+/// Maps a server error in response to a submission to the reason for rejection, if any.
+fn map_rejection(err: RpcError) -> RpcError {
+    match err {
+        RpcError::Server(error) => match InvalidTransactionError::try_from_server_error(error) {
+            Ok(err) => RpcError::InvalidTransaction(err),
+            Err(error) => RpcError::Server(error),
+        },
+        err => err,
+    }
+}
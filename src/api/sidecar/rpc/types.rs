@@ -0,0 +1,399 @@
+use casper_types::{
+    account::{Account, AccountHash},
+    contracts::ContractHash,
+    system::auction::{Bid, EraValidators},
+    AddressableEntity, AvailableBlockRange, Block, BlockHash, BlockHeader, BlockIdentifier,
+    BlockSynchronizerStatus, BlockTime, ByteCode, Contract, ContractWasm, Deploy, DictionaryAddr,
+    Digest, EntityAddr, EntryPointValue, EraId, ExecutionInfo, HashAddr, Key, NamedKeys,
+    NextUpgrade, Peers, ProtocolVersion, PublicKey, Signature, StoredValue, TimeDiff, Timestamp,
+    Transaction, Transfer, URef, U512,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// Status of the node behind a sidecar, as returned by `info_get_status`.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct NodeStatus {
+    pub protocol_version: ProtocolVersion,
+    pub peers: Peers,
+    pub chainspec_name: String,
+    pub starting_state_root_hash: Digest,
+    pub last_added_block_info: Option<MinimalBlockInfo>,
+    pub our_public_signing_key: Option<PublicKey>,
+    pub round_length: Option<TimeDiff>,
+    pub next_upgrade: Option<NextUpgrade>,
+    pub uptime: TimeDiff,
+    pub reactor_state: String,
+    pub last_progress: Timestamp,
+    pub available_block_range: AvailableBlockRange,
+    pub block_sync: BlockSynchronizerStatus,
+    pub latest_switch_block_hash: Option<BlockHash>,
+    pub build_version: String,
+}
+
+/// Summary of the block most recently added to the node's linear chain.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct MinimalBlockInfo {
+    pub hash: BlockHash,
+    pub timestamp: Timestamp,
+    pub era_id: EraId,
+    pub height: u64,
+    pub state_root_hash: Digest,
+    pub creator: PublicKey,
+}
+
+/// Identifies an era either directly or by a block within it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum EraIdentifier {
+    Era(EraId),
+    Block(BlockIdentifier),
+}
+
+/// Reward of a validator, or of a delegator, in an era.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Reward {
+    #[serde(rename = "reward_amount")]
+    pub amount: U512,
+    pub era_id: EraId,
+    pub delegation_rate: u8,
+    pub switch_block_hash: BlockHash,
+}
+
+/// A transaction along with where, and with what result, it was executed, if it has been.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct TransactionWithExecutionInfo {
+    pub transaction: Transaction,
+    pub execution_info: Option<ExecutionInfo>,
+}
+
+/// A legacy deploy along with where, and with what result, it was executed, if it has been.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct DeployWithExecutionInfo {
+    pub deploy: Deploy,
+    pub execution_info: Option<ExecutionInfo>,
+}
+
+/// A block along with the signatures of its hash known to the node, by validator.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct BlockWithSignatures {
+    pub block: Block,
+    #[serde(deserialize_with = "deserialize_proofs")]
+    pub proofs: BTreeMap<PublicKey, Signature>,
+}
+
+/// Transfers executed within a block, or `None` if the node lacks either.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct BlockTransfers {
+    pub block_hash: Option<BlockHash>,
+    pub transfers: Option<Vec<Transfer>>,
+}
+
+/// Summary of an era, as stored in global state under the state root hash of a block.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct EraSummary {
+    pub block_hash: BlockHash,
+    pub era_id: EraId,
+    pub stored_value: StoredValue,
+    pub state_root_hash: Digest,
+    /// Hex encoded merkle proof of the stored value.
+    pub merkle_proof: String,
+}
+
+/// Identifies a purse, either directly or as the main purse of an account or entity.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum PurseIdentifier {
+    MainPurseUnderPublicKey(PublicKey),
+    MainPurseUnderAccountHash(AccountHash),
+    MainPurseUnderEntityAddr(EntityAddr),
+    PurseUref(URef),
+}
+
+/// Identifies an account by its public key or account hash.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum AccountIdentifier {
+    PublicKey(PublicKey),
+    AccountHash(AccountHash),
+}
+
+/// Identifies an addressable entity, or a legacy account or contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum EntityIdentifier {
+    ContractHash(ContractHash),
+    PublicKey(PublicKey),
+    AccountHash(AccountHash),
+    EntityAddr(EntityAddr),
+}
+
+/// Identifies a dictionary item, either by a named key of the account, contract or entity
+/// holding the dictionary, by the dictionary's seed uref, or by its address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DictionaryIdentifier {
+    AccountNamedKey {
+        hash: AccountHash,
+        dictionary_name: String,
+        dictionary_item_key: String,
+    },
+    ContractNamedKey {
+        hash: HashAddr,
+        dictionary_name: String,
+        dictionary_item_key: String,
+    },
+    EntityNamedKey {
+        addr: EntityAddr,
+        dictionary_name: String,
+        dictionary_item_key: String,
+    },
+    URef {
+        seed_uref: URef,
+        dictionary_item_key: String,
+    },
+    Dictionary(DictionaryAddr),
+}
+
+/// Encodes as the sidecar expects, i.e. with keys and urefs as formatted strings.
+impl Serialize for DictionaryIdentifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        enum Params<'a> {
+            AccountNamedKey {
+                key: String,
+                dictionary_name: &'a str,
+                dictionary_item_key: &'a str,
+            },
+            ContractNamedKey {
+                key: String,
+                dictionary_name: &'a str,
+                dictionary_item_key: &'a str,
+            },
+            EntityNamedKey {
+                key: String,
+                dictionary_name: &'a str,
+                dictionary_item_key: &'a str,
+            },
+            URef {
+                seed_uref: String,
+                dictionary_item_key: &'a str,
+            },
+            Dictionary(String),
+        }
+
+        let params = match self {
+            DictionaryIdentifier::AccountNamedKey {
+                hash,
+                dictionary_name,
+                dictionary_item_key,
+            } => Params::AccountNamedKey {
+                key: Key::Account(*hash).to_formatted_string(),
+                dictionary_name,
+                dictionary_item_key,
+            },
+            DictionaryIdentifier::ContractNamedKey {
+                hash,
+                dictionary_name,
+                dictionary_item_key,
+            } => Params::ContractNamedKey {
+                key: Key::Hash(*hash).to_formatted_string(),
+                dictionary_name,
+                dictionary_item_key,
+            },
+            DictionaryIdentifier::EntityNamedKey {
+                addr,
+                dictionary_name,
+                dictionary_item_key,
+            } => Params::EntityNamedKey {
+                key: Key::AddressableEntity(*addr).to_formatted_string(),
+                dictionary_name,
+                dictionary_item_key,
+            },
+            DictionaryIdentifier::URef {
+                seed_uref,
+                dictionary_item_key,
+            } => Params::URef {
+                seed_uref: seed_uref.to_formatted_string(),
+                dictionary_item_key,
+            },
+            DictionaryIdentifier::Dictionary(addr) => {
+                Params::Dictionary(Key::Dictionary(*addr).to_formatted_string())
+            }
+        };
+        params.serialize(serializer)
+    }
+}
+
+/// A global state entry along with its merkle proof, and the header of the block whose state
+/// was queried, if queried by block.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct StoredValueWithProof {
+    #[serde(default)]
+    pub block_header: Option<BlockHeader>,
+    pub stored_value: StoredValue,
+    /// Hex encoded merkle proof of the stored value.
+    pub merkle_proof: String,
+}
+
+/// A dictionary item along with its key & merkle proof.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct DictionaryItem {
+    pub dictionary_key: Key,
+    pub stored_value: StoredValue,
+    /// Hex encoded merkle proof of the stored value.
+    pub merkle_proof: String,
+}
+
+/// Balance of a purse along with its merkle proof.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct BalanceWithProof {
+    #[serde(rename = "balance_value")]
+    pub balance: U512,
+    /// Hex encoded merkle proof of the balance.
+    pub merkle_proof: String,
+}
+
+/// Total & available balance of a purse, the latter net of holds upon the purse.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct BalanceDetails {
+    pub total_balance: U512,
+    pub available_balance: U512,
+    /// Hex encoded merkle proof of the total balance.
+    pub total_balance_proof: String,
+    pub holds: Vec<BalanceHold>,
+}
+
+/// Amount of a purse held since a block time.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct BalanceHold {
+    pub time: BlockTime,
+    pub amount: U512,
+    /// Hex encoded merkle proof of the hold.
+    pub proof: String,
+}
+
+/// A legacy account along with its merkle proof.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct AccountWithProof {
+    pub account: Account,
+    /// Hex encoded merkle proof of the account.
+    pub merkle_proof: String,
+}
+
+/// An addressable entity, or a legacy account or contract, along with its merkle proof.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct EntityWithProof {
+    pub entity: Entity,
+    /// Hex encoded merkle proof of the entity.
+    pub merkle_proof: String,
+}
+
+/// An addressable entity, or a legacy account or contract.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub enum Entity {
+    AddressableEntity {
+        entity: AddressableEntity,
+        named_keys: NamedKeys,
+        entry_points: Vec<EntryPointValue>,
+        /// Byte code of the entity, if requested.
+        bytecode: Option<ByteCodeWithProof>,
+    },
+    Account(Account),
+    Contract {
+        contract: Contract,
+        /// Wasm of the contract, if requested.
+        wasm: Option<ContractWasmWithProof>,
+    },
+}
+
+/// Byte code of an addressable entity along with its merkle proof.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct ByteCodeWithProof {
+    pub code: ByteCode,
+    /// Hex encoded merkle proof of the byte code.
+    pub merkle_proof: String,
+}
+
+/// Wasm of a legacy contract along with its merkle proof.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct ContractWasmWithProof {
+    pub wasm: ContractWasm,
+    /// Hex encoded merkle proof of the wasm.
+    pub merkle_proof: String,
+}
+
+/// State of the auction as at a block.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct AuctionState {
+    pub state_root_hash: Digest,
+    pub block_height: u64,
+    /// Weights of validators, by era.
+    #[serde(deserialize_with = "deserialize_era_validators")]
+    pub era_validators: EraValidators,
+    /// Bid of each validator, including those delegated to it.
+    #[serde(deserialize_with = "deserialize_bids")]
+    pub bids: BTreeMap<PublicKey, Bid>,
+}
+
+/// Deserializes validator weights, encoded by era as an array of weights per validator.
+fn deserialize_era_validators<'de, D>(deserializer: D) -> Result<EraValidators, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct ValidatorWeight {
+        public_key: PublicKey,
+        weight: U512,
+    }
+    #[derive(Deserialize)]
+    struct EraValidatorWeights {
+        era_id: EraId,
+        validator_weights: Vec<ValidatorWeight>,
+    }
+
+    let era_validators = Vec::<EraValidatorWeights>::deserialize(deserializer)?;
+    Ok(era_validators
+        .into_iter()
+        .map(|era| {
+            let weights = era
+                .validator_weights
+                .into_iter()
+                .map(|weight| (weight.public_key, weight.weight))
+                .collect();
+            (era.era_id, weights)
+        })
+        .collect())
+}
+
+/// Deserializes bids, encoded as an array of public key & bid pairs.
+fn deserialize_bids<'de, D>(deserializer: D) -> Result<BTreeMap<PublicKey, Bid>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct ValidatorBid {
+        public_key: PublicKey,
+        bid: Bid,
+    }
+
+    let bids = Vec::<ValidatorBid>::deserialize(deserializer)?;
+    Ok(bids
+        .into_iter()
+        .map(|bid| (bid.public_key, bid.bid))
+        .collect())
+}
+
+/// Deserializes block proofs, encoded as an array of public key & signature pairs.
+fn deserialize_proofs<'de, D>(deserializer: D) -> Result<BTreeMap<PublicKey, Signature>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct BlockProof {
+        public_key: PublicKey,
+        signature: Signature,
+    }
+
+    let proofs = Vec::<BlockProof>::deserialize(deserializer)?;
+    Ok(proofs
+        .into_iter()
+        .map(|proof| (proof.public_key, proof.signature))
+        .collect())
+}
@@ -0,0 +1,168 @@
+//! Verification of blocks and of chains of block headers.
+//!
+//! A block hash is the blake2b hash of the block's header, which in turn commits to the block's
+//! body, i.e. its transactions, via the body hash.  Headers link to their parent by hash, and
+//! each switch block, i.e. the last block of an era, carries the weights of the validators of the
+//! next era.  Starting from a trusted header, each following header can thus be verified, as can
+//! the proposer of each block within an era whose validators are known.
+
+use casper_types::{Block, BlockHash, BlockHeader, EraId, PublicKey, U512};
+use std::collections::BTreeMap;
+
+use super::error::ChainError;
+
+/// Verifies a block's hash against its header, and its body hash against its transactions.
+pub fn verify_block(block: &Block) -> Result<(), ChainError> {
+    block.verify()?;
+    Ok(())
+}
+
+/// Verifies that a header hashes to the expected block hash.
+pub fn verify_block_hash(header: &BlockHeader, expected: &BlockHash) -> Result<(), ChainError> {
+    let got = header.block_hash();
+    if got != *expected {
+        return Err(ChainError::BlockHashMismatch {
+            expected: *expected,
+            got,
+        });
+    }
+    Ok(())
+}
+
+/// Verifies that headers, ordered by height, form a chain, trusting the first.
+pub fn verify_header_chain(headers: &[BlockHeader]) -> Result<(), ChainError> {
+    let Some((trusted, headers)) = headers.split_first() else {
+        return Ok(());
+    };
+    HeaderChainVerifier::new(trusted.clone()).verify_all(headers)
+}
+
+/// Extends a trusted header with headers verified to follow it.
+#[derive(Clone, Debug)]
+pub struct HeaderChainVerifier {
+    latest: BlockHeader,
+    latest_hash: BlockHash,
+    last_switch_block_hash: Option<BlockHash>,
+    validator_weights: Option<(EraId, BTreeMap<PublicKey, U512>)>,
+}
+
+impl HeaderChainVerifier {
+    /// Creates a verifier anchored at a trusted header.
+    ///
+    /// Validators are known for the era following a trusted switch block, otherwise from the
+    /// first switch block verified.
+    pub fn new(trusted: BlockHeader) -> Self {
+        let latest_hash = trusted.block_hash();
+        let last_switch_block_hash = if trusted.is_switch_block() {
+            Some(latest_hash)
+        } else if let BlockHeader::V2(header) = &trusted {
+            header.last_switch_block_hash()
+        } else {
+            None
+        };
+        let validator_weights = trusted
+            .next_era_validator_weights()
+            .map(|weights| (trusted.next_block_era_id(), weights.clone()));
+
+        HeaderChainVerifier {
+            latest: trusted,
+            latest_hash,
+            last_switch_block_hash,
+            validator_weights,
+        }
+    }
+
+    /// Sets trusted weights of the validators of an era, e.g. those of the trusted header's era.
+    pub fn with_validator_weights(
+        mut self,
+        era_id: EraId,
+        weights: BTreeMap<PublicKey, U512>,
+    ) -> Self {
+        self.validator_weights = Some((era_id, weights));
+        self
+    }
+
+    /// Returns the latest verified, or the trusted, header.
+    pub fn latest(&self) -> &BlockHeader {
+        &self.latest
+    }
+
+    /// Returns weights of the validators of an era, if known.
+    pub fn validator_weights(&self, era_id: EraId) -> Option<&BTreeMap<PublicKey, U512>> {
+        match &self.validator_weights {
+            Some((weights_era_id, weights)) if *weights_era_id == era_id => Some(weights),
+            _ => None,
+        }
+    }
+
+    /// Verifies that a header follows the latest header, which it then becomes.
+    pub fn verify_next(&mut self, header: BlockHeader) -> Result<(), ChainError> {
+        let height = header.height();
+        let expected_height = self.latest.height() + 1;
+        if height != expected_height {
+            return Err(ChainError::HeightMismatch {
+                expected: expected_height,
+                got: height,
+            });
+        }
+        if *header.parent_hash() != self.latest_hash {
+            return Err(ChainError::ParentHashMismatch {
+                height,
+                expected: self.latest_hash,
+                got: *header.parent_hash(),
+            });
+        }
+        let expected_era_id = self.latest.next_block_era_id();
+        if header.era_id() != expected_era_id {
+            return Err(ChainError::EraMismatch {
+                height,
+                expected: expected_era_id,
+                got: header.era_id(),
+            });
+        }
+        if let BlockHeader::V2(header_v2) = &header {
+            if let Some(expected) = self.last_switch_block_hash {
+                if header_v2.last_switch_block_hash() != Some(expected) {
+                    return Err(ChainError::LastSwitchBlockMismatch {
+                        height,
+                        expected,
+                        got: header_v2.last_switch_block_hash(),
+                    });
+                }
+            }
+            // Immediate switch blocks, following an upgrade, are proposed by the system.
+            let proposer = header_v2.proposer();
+            if let Some(weights) = self.validator_weights(header.era_id()) {
+                if *proposer != PublicKey::System && !weights.contains_key(proposer) {
+                    return Err(ChainError::UnknownProposer {
+                        height,
+                        proposer: Box::new(proposer.clone()),
+                    });
+                }
+            }
+        }
+
+        let hash = header.block_hash();
+        if header.is_switch_block() {
+            let Some(weights) = header.next_era_validator_weights() else {
+                return Err(ChainError::MissingValidatorWeights { height });
+            };
+            self.validator_weights = Some((header.next_block_era_id(), weights.clone()));
+            self.last_switch_block_hash = Some(hash);
+        }
+        self.latest = header;
+        self.latest_hash = hash;
+
+        Ok(())
+    }
+
+    /// Verifies headers, ordered by height, as following the latest header.
+    pub fn verify_all<'a>(
+        &mut self,
+        headers: impl IntoIterator<Item = &'a BlockHeader>,
+    ) -> Result<(), ChainError> {
+        headers
+            .into_iter()
+            .try_for_each(|header| self.verify_next(header.clone()))
+    }
+}
@@ -0,0 +1,100 @@
+use casper_types::{
+    bytesrepr, crypto, BlockHash, BlockValidationError, Digest, EraId, Key, PublicKey, U512,
+};
+use thiserror::Error;
+
+/// Error whilst verifying a merkle proof of a global state entry.
+#[derive(Error, Debug)]
+pub enum ProofError {
+    #[error("Failed to decode merkle proof: {0}")]
+    DecodeError(#[from] bytesrepr::Error),
+
+    #[error("Failed to decode hex encoded merkle proof: {0}")]
+    HexError(#[from] base16::DecodeError),
+
+    #[error("No merkle proof")]
+    EmptyProof,
+
+    #[error(
+        "Merkle proof does not lead to trusted state root hash: expected {expected}, got {got}"
+    )]
+    StateRootHashMismatch { expected: Digest, got: Digest },
+
+    #[error("Merkle proof is for key {got}, expected {expected}")]
+    KeyMismatch { expected: Box<Key>, got: Box<Key> },
+
+    #[error("Merkle proof is for a value other than that returned")]
+    ValueMismatch,
+
+    /// Proven value does not resolve the next name of the queried path.
+    #[error("Merkle proof of {key} does not resolve name {name}")]
+    UnresolvedName { key: Box<Key>, name: String },
+
+    /// Proofs end before the queried path does, or continue beyond it.
+    #[error("Merkle proofs do not end where the queried path does, at {key}")]
+    PathMismatch { key: Box<Key> },
+}
+
+/// Error whilst verifying a block, or the linkage of a chain of block headers.
+#[derive(Error, Debug)]
+pub enum ChainError {
+    #[error("Invalid block: {0}")]
+    InvalidBlock(#[source] Box<BlockValidationError>),
+
+    #[error("Block hash mismatch: expected {expected}, got {got}")]
+    BlockHashMismatch { expected: BlockHash, got: BlockHash },
+
+    #[error("Block height mismatch: expected {expected}, got {got}")]
+    HeightMismatch { expected: u64, got: u64 },
+
+    #[error("Block {height} does not link to its parent: expected parent {expected}, got {got}")]
+    ParentHashMismatch {
+        height: u64,
+        expected: BlockHash,
+        got: BlockHash,
+    },
+
+    #[error("Block {height} era mismatch: expected {expected}, got {got}")]
+    EraMismatch {
+        height: u64,
+        expected: EraId,
+        got: EraId,
+    },
+
+    #[error("Block {height} last switch block mismatch: expected {expected}, got {got:?}")]
+    LastSwitchBlockMismatch {
+        height: u64,
+        expected: BlockHash,
+        got: Option<BlockHash>,
+    },
+
+    #[error("Switch block {height} lacks validator weights for the next era")]
+    MissingValidatorWeights { height: u64 },
+
+    #[error("Block {height} proposed by {proposer}, who is not a validator in its era")]
+    UnknownProposer {
+        height: u64,
+        proposer: Box<PublicKey>,
+    },
+    #[error("Finality signatures are for block {got}, expected {expected}")]
+    SignaturesMismatch { expected: BlockHash, got: BlockHash },
+
+    #[error("Invalid finality signature: {0}")]
+    InvalidSignature(#[source] crypto::Error),
+
+    #[error("Insufficient finality signature weight for block {height}: {signed} of {total}")]
+    InsufficientSignatureWeight {
+        height: u64,
+        signed: Box<U512>,
+        total: Box<U512>,
+    },
+
+    #[error("Validators of era {0} are unknown")]
+    UnknownValidators(EraId),
+}
+
+impl From<BlockValidationError> for ChainError {
+    fn from(err: BlockValidationError) -> Self {
+        ChainError::InvalidBlock(Box::new(err))
+    }
+}
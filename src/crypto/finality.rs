@@ -0,0 +1,85 @@
+//! Verification of finality signatures.
+//!
+//! Validators sign each block they deem final.  A block signed by validators whose combined
+//! weight, within the block's era, exceeds the finality threshold can be trusted without
+//! verifying its linkage to a trusted block.
+
+use casper_types::{BlockHeader, BlockSignatures, PublicKey, U512};
+use std::collections::BTreeMap;
+
+use super::error::ChainError;
+
+/// Fraction of the total validator weight which signatures must exceed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FinalityThreshold {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl FinalityThreshold {
+    /// Node's default fault tolerance, i.e. signatures exceeding a third of the weight.
+    pub const ONE_THIRD: FinalityThreshold = FinalityThreshold {
+        numerator: 1,
+        denominator: 3,
+    };
+
+    /// Strict finality, i.e. signatures exceeding two thirds of the weight.
+    pub const TWO_THIRDS: FinalityThreshold = FinalityThreshold {
+        numerator: 2,
+        denominator: 3,
+    };
+
+    /// Predicate: returns true if signed weight exceeds the threshold of the total weight.
+    pub fn is_exceeded(&self, signed: U512, total: U512) -> bool {
+        signed * U512::from(self.denominator) > total * U512::from(self.numerator)
+    }
+}
+
+impl Default for FinalityThreshold {
+    fn default() -> Self {
+        FinalityThreshold::ONE_THIRD
+    }
+}
+
+/// Verifies that signatures of a header are valid and, counting only signatures of validators
+/// within `validator_weights`, i.e. those of the header's era, exceed the threshold.
+pub fn verify_finality_signatures(
+    header: &BlockHeader,
+    signatures: &BlockSignatures,
+    validator_weights: &BTreeMap<PublicKey, U512>,
+    threshold: FinalityThreshold,
+) -> Result<(), ChainError> {
+    let block_hash = header.block_hash();
+    if *signatures.block_hash() != block_hash {
+        return Err(ChainError::SignaturesMismatch {
+            expected: block_hash,
+            got: *signatures.block_hash(),
+        });
+    }
+    if signatures.era_id() != header.era_id() {
+        return Err(ChainError::EraMismatch {
+            height: header.height(),
+            expected: header.era_id(),
+            got: signatures.era_id(),
+        });
+    }
+    signatures
+        .is_verified()
+        .map_err(ChainError::InvalidSignature)?;
+
+    let total = validator_weights.values().copied().sum::<U512>();
+    let signed = signatures
+        .signers()
+        .filter_map(|signer| validator_weights.get(signer))
+        .copied()
+        .sum::<U512>();
+    if !threshold.is_exceeded(signed, total) {
+        return Err(ChainError::InsufficientSignatureWeight {
+            height: header.height(),
+            signed: Box::new(signed),
+            total: Box::new(total),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+//! Verification of merkle proofs of global state entries.
+//!
+//! Global state is a merkle trie whose root hash is committed to by each block header.  A node
+//! returns, alongside a queried value, proofs from which the root hash of the trie holding the
+//! value can be recomputed.  Checked against the state root hash of a trusted block header, e.g.
+//! one verified against finality signatures, a value read from an untrusted node can be trusted.
+
+use casper_types::{
+    addressable_entity::NamedKeyAddr,
+    bytesrepr::{self, ToBytes},
+    checksummed_hex,
+    global_state::{Pointer, TrieMerkleProof, TrieMerkleProofStep},
+    handle_stored_dictionary_value, CLType, Digest, Key, StoredValue,
+};
+use l1_binary_port::{DictionaryQueryResult, GlobalStateQueryResult};
+
+use super::error::ProofError;
+use crate::api::node::binary::types::Trie;
+
+/// Merkle proof of a global state entry.
+pub type StateProof = TrieMerkleProof<Key, StoredValue>;
+
+/// Returns root hash of the trie which, as per the proof, holds its key & value.
+pub fn compute_state_root_hash(proof: &StateProof) -> Result<Digest, bytesrepr::Error> {
+    let leaf = Trie::Leaf {
+        key: *proof.key(),
+        value: Box::new(proof.value().clone()),
+    };
+    let mut hash = Digest::hash_into_chunks_if_necessary(&leaf.to_bytes()?);
+
+    for (index, step) in proof.proof_steps().iter().enumerate() {
+        // Walking from the leaf up, the first step points at the leaf, all others at a node.
+        let pointer = if index == 0 {
+            Pointer::LeafPointer(hash)
+        } else {
+            Pointer::NodePointer(hash)
+        };
+        let trie = match step {
+            TrieMerkleProofStep::Node {
+                hole_index,
+                indexed_pointers_with_hole,
+            } => {
+                let mut pointers = indexed_pointers_with_hole.clone();
+                pointers.push((*hole_index, pointer));
+                Trie::Node { pointers }
+            }
+            TrieMerkleProofStep::Extension { affix } => Trie::Extension {
+                affix: affix.clone(),
+                pointer,
+            },
+        };
+        hash = Digest::hash_into_chunks_if_necessary(&trie.to_bytes()?);
+    }
+
+    Ok(hash)
+}
+
+/// Verifies that the proof leads to the trusted state root hash.
+pub fn verify_proof(proof: &StateProof, state_root_hash: &Digest) -> Result<(), ProofError> {
+    let computed = compute_state_root_hash(proof)?;
+    if computed != *state_root_hash {
+        return Err(ProofError::StateRootHashMismatch {
+            expected: *state_root_hash,
+            got: computed,
+        });
+    }
+    Ok(())
+}
+
+/// Verifies the proofs of a query for the value under a key, possibly following a path of named
+/// keys, one proof per key visited.
+///
+/// Each proof must lead to the trusted state root hash.  The first must be of the queried key,
+/// each other of the key to which its predecessor's value leads, as the node resolves the path,
+/// and the last of the returned value.
+pub fn verify_proofs(
+    proofs: &[StateProof],
+    key: &Key,
+    path: &[String],
+    value: &StoredValue,
+    state_root_hash: &Digest,
+) -> Result<(), ProofError> {
+    let (Some(first), Some(last)) = (proofs.first(), proofs.last()) else {
+        return Err(ProofError::EmptyProof);
+    };
+    check_key(first, key.normalize())?;
+    proofs
+        .iter()
+        .try_for_each(|proof| verify_proof(proof, state_root_hash))?;
+
+    let mut walk = PathWalk {
+        names: path.iter(),
+        visited_name: None,
+    };
+    for (index, proof) in proofs.iter().enumerate() {
+        match (walk.next_key(proof)?, proofs.get(index + 1)) {
+            (Some(next_key), Some(next)) => check_key(next, next_key)?,
+            (None, None) => (),
+            // Proofs end before the path does, or continue beyond it.
+            (Some(_), None) | (None, Some(_)) => {
+                return Err(ProofError::PathMismatch {
+                    key: Box::new(*proof.key()),
+                })
+            }
+        }
+    }
+
+    // Dictionary values are returned unwrapped, but proven as stored.
+    match handle_stored_dictionary_value(*last.key(), last.value().clone()) {
+        Ok(proven) if proven == *value => Ok(()),
+        _ => Err(ProofError::ValueMismatch),
+    }
+}
+
+/// Verifies a global state query result for the value under a key, possibly following a path of
+/// named keys, returning the value.
+pub fn verify_query_result(
+    result: GlobalStateQueryResult,
+    key: &Key,
+    path: &[String],
+    state_root_hash: &Digest,
+) -> Result<StoredValue, ProofError> {
+    let (value, proofs) = result.into_inner();
+    verify_proofs(&proofs, key, path, &value, state_root_hash)?;
+    Ok(value)
+}
+
+/// Verifies a dictionary query result for the item under `dictionary_key`, as derived from the
+/// requested identifier, returning the item's key & value.
+pub fn verify_dictionary_query_result(
+    result: DictionaryQueryResult,
+    dictionary_key: &Key,
+    state_root_hash: &Digest,
+) -> Result<(Key, StoredValue), ProofError> {
+    let (key, result) = result.into_inner();
+    if key != *dictionary_key {
+        return Err(ProofError::KeyMismatch {
+            expected: Box::new(*dictionary_key),
+            got: Box::new(key),
+        });
+    }
+    let value = verify_query_result(result, &key, &[], state_root_hash)?;
+    Ok((key, value))
+}
+
+/// Returns key of a dictionary item, given the verified account, contract or named key entry
+/// under `key` within whose named keys the dictionary's seed URef is named.
+pub fn named_dictionary_key(
+    key: &Key,
+    value: &StoredValue,
+    dictionary_name: &str,
+    dictionary_item_key: &str,
+) -> Result<Key, ProofError> {
+    let seed = match value {
+        StoredValue::Account(account) => account.named_keys().get(dictionary_name).copied(),
+        StoredValue::Contract(contract) => contract.named_keys().get(dictionary_name).copied(),
+        StoredValue::NamedKey(named_key)
+            if named_key.get_name().ok().as_deref() == Some(dictionary_name) =>
+        {
+            named_key.get_key().ok()
+        }
+        _ => None,
+    };
+    match seed {
+        Some(Key::URef(seed_uref)) => {
+            Ok(Key::dictionary(seed_uref, dictionary_item_key.as_bytes()))
+        }
+        _ => Err(ProofError::UnresolvedName {
+            key: Box::new(*key),
+            name: dictionary_name.to_string(),
+        }),
+    }
+}
+
+fn check_key(proof: &StateProof, expected: Key) -> Result<(), ProofError> {
+    if proof.key().normalize() != expected {
+        return Err(ProofError::KeyMismatch {
+            expected: Box::new(expected),
+            got: Box::new(*proof.key()),
+        });
+    }
+    Ok(())
+}
+
+/// Resolution of a path of named keys, as the node's global state query does.
+struct PathWalk<'a> {
+    names: std::slice::Iter<'a, String>,
+    visited_name: Option<&'a String>,
+}
+
+impl PathWalk<'_> {
+    /// Returns the key to which the proven entry leads, or `None` if the query ends there.
+    fn next_key(&mut self, proof: &StateProof) -> Result<Option<Key>, ProofError> {
+        let key = *proof.key();
+        let unresolved = |name: &String| ProofError::UnresolvedName {
+            key: Box::new(key),
+            name: name.clone(),
+        };
+        if self.names.as_slice().is_empty() && !key.is_named_key() {
+            return Ok(None);
+        }
+        let next_key = match proof.value() {
+            StoredValue::Account(account) => {
+                let name = self.next_name(key)?;
+                *account
+                    .named_keys()
+                    .get(name)
+                    .ok_or_else(|| unresolved(name))?
+            }
+            StoredValue::Contract(contract) => {
+                let name = self.next_name(key)?;
+                *contract
+                    .named_keys()
+                    .get(name)
+                    .ok_or_else(|| unresolved(name))?
+            }
+            StoredValue::AddressableEntity(_) => {
+                let name = self.next_name(key)?;
+                let Key::AddressableEntity(entity_addr) = key else {
+                    return Err(unresolved(name));
+                };
+                NamedKeyAddr::new_from_string(entity_addr, name.clone())
+                    .map(Key::NamedKey)
+                    .map_err(|_| unresolved(name))?
+            }
+            StoredValue::NamedKey(named_key) => match self.visited_name {
+                Some(name) => match (named_key.get_name(), named_key.get_key()) {
+                    (Ok(actual), Ok(next_key)) if actual == *name => next_key,
+                    _ => return Err(unresolved(name)),
+                },
+                // Named key entry queried directly.
+                None if self.names.as_slice().is_empty() => return Ok(None),
+                None => return Err(ProofError::PathMismatch { key: Box::new(key) }),
+            },
+            StoredValue::CLValue(value) if *value.cl_type() == CLType::Key => value
+                .clone()
+                .into_t()
+                .map_err(|_| ProofError::PathMismatch { key: Box::new(key) })?,
+            _ => return Err(unresolved(self.next_name(key)?)),
+        };
+        Ok(Some(next_key.normalize()))
+    }
+
+    fn next_name(&mut self, key: Key) -> Result<&String, ProofError> {
+        let name = self
+            .names
+            .next()
+            .ok_or(ProofError::PathMismatch { key: Box::new(key) })?;
+        self.visited_name = Some(name);
+        Ok(name)
+    }
+}
+
+/// Decodes proofs as serialized by the node, e.g. as hex encoded within a JSON-RPC response.
+pub fn decode_proofs(bytes: &[u8]) -> Result<Vec<StateProof>, ProofError> {
+    Ok(bytesrepr::deserialize_from_slice(bytes)?)
+}
+
+/// Decodes hex encoded proofs, as returned by the sidecar's JSON-RPC API.
+pub fn decode_hex_proofs(hex: &str) -> Result<Vec<StateProof>, ProofError> {
+    decode_proofs(&checksummed_hex::decode(hex)?)
+}
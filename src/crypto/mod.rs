@@ -0,0 +1,8 @@
+pub mod chain;
+pub mod error;
+pub mod finality;
+pub mod merkle;
+
+pub use chain::HeaderChainVerifier;
+pub use error::{ChainError, ProofError};
+pub use finality::FinalityThreshold;
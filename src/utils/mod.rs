@@ -1 +1 @@
-
+pub(crate) mod trace;
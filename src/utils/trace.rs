@@ -0,0 +1,13 @@
+//! Forwards to `tracing` when the `tracing` feature is enabled, otherwise expands to nothing.
+//!
+//! Usage mirrors the `tracing` event macros, with the level as first argument, e.g.
+//! `trace_event!(debug, endpoint = %url, "connecting")`.
+
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::$level!($($arg)+);
+    };
+}
+
+pub(crate) use trace_event;
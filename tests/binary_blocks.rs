@@ -0,0 +1,259 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        client::{BlockRangeOptions, FetchedBlock},
+        error::{ProtocolError, TransportError},
+        proxy::{MockProxy, RequestMatcher},
+        BinaryError, Client,
+    };
+    use casper_types::{
+        bytesrepr::ToBytes, execution::ExecutionResult, testing::TestRng, AvailableBlockRange,
+        Block, BlockIdentifier, TestBlockBuilder, Transaction,
+    };
+    use futures::StreamExt;
+    use l1_binary_port::{Command, GetRequest, InformationRequest, RecordId};
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    fn record_request(record_id: RecordId, key: impl ToBytes) -> GetRequest {
+        GetRequest::Record {
+            record_type_tag: record_id.into(),
+            key: key.to_bytes().unwrap(),
+        }
+    }
+
+    fn information_request(req: InformationRequest) -> GetRequest {
+        req.try_into().unwrap()
+    }
+
+    fn block_header_request(height: u64) -> GetRequest {
+        information_request(InformationRequest::BlockHeader(Some(
+            BlockIdentifier::Height(height),
+        )))
+    }
+
+    fn get_block(rng: &mut TestRng, height: u64, transactions: &[Transaction]) -> Block {
+        Block::from(
+            TestBlockBuilder::new()
+                .height(height)
+                .transactions(transactions)
+                .build(rng),
+        )
+    }
+
+    /// Answers header & body requests for a block.
+    fn with_block(proxy: MockProxy, block: &Block) -> MockProxy {
+        proxy
+            .with_value(block_header_request(block.height()), block.clone_header())
+            .with_value(
+                record_request(RecordId::BlockBody, *block.body_hash()),
+                block.clone().take_body(),
+            )
+    }
+
+    fn with_available_range(proxy: MockProxy, low: u64, high: u64) -> MockProxy {
+        proxy.with_value(
+            information_request(InformationRequest::AvailableBlockRange),
+            AvailableBlockRange::new(low, high),
+        )
+    }
+
+    fn options() -> BlockRangeOptions {
+        BlockRangeOptions {
+            parallelism: 4,
+            retry_delay: Duration::ZERO,
+            ..BlockRangeOptions::default()
+        }
+    }
+
+    async fn fetch_heights(client: &Client, range: impl std::ops::RangeBounds<u64>) -> Vec<u64> {
+        client
+            .blocks(range, options())
+            .map(|result| result.unwrap().block.height())
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_blocks_in_order_within_available_range() {
+        let rng = &mut TestRng::new();
+        let mut proxy = with_available_range(MockProxy::new(), 2, 7);
+        for height in 0..10 {
+            let block = get_block(rng, height, &[]);
+            // Earlier blocks take longer, so complete out of order.
+            proxy = with_block(proxy, &block).with_latency(
+                block_header_request(height),
+                Duration::from_millis(50 - 5 * height),
+            );
+        }
+        let client = Client::new(Arc::new(proxy));
+
+        assert_eq!(
+            fetch_heights(&client, ..).await,
+            (2..=7).collect::<Vec<_>>()
+        );
+        assert_eq!(fetch_heights(&client, 0..=4).await, vec![2, 3, 4]);
+        assert_eq!(fetch_heights(&client, 4..6).await, vec![4, 5]);
+        assert!(fetch_heights(&client, 8..).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_blocks_outside_available_range() {
+        let proxy = with_available_range(MockProxy::new(), 5, u64::MAX);
+        let client = Client::new(Arc::new(proxy));
+
+        // No block is requested, even as the available range reaches the highest height.
+        assert!(fetch_heights(&client, 0..5).await.is_empty());
+        assert!(fetch_heights(&client, ..=2).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_blocks_with_transactions_and_execution_results() {
+        let rng = &mut TestRng::new();
+        let transactions: Vec<Transaction> = (0..3).map(|_| Transaction::random(rng)).collect();
+        let execution_results: Vec<ExecutionResult> =
+            (0..3).map(|_| ExecutionResult::random(rng)).collect();
+        let block = get_block(rng, 5, &transactions);
+        let mut proxy = with_block(with_available_range(MockProxy::new(), 0, 5), &block);
+        for (transaction, execution_result) in transactions.iter().zip(&execution_results) {
+            proxy = proxy
+                .with_value(
+                    record_request(RecordId::Transaction, transaction.hash()),
+                    transaction.clone(),
+                )
+                .with_value(
+                    record_request(RecordId::ExecutionResult, transaction.hash()),
+                    execution_result.clone(),
+                );
+        }
+        let client = Client::new(Arc::new(proxy));
+
+        let options = BlockRangeOptions {
+            include_transactions: true,
+            include_execution_results: true,
+            ..options()
+        };
+        let fetched: Vec<FetchedBlock> = client
+            .blocks(5..=5, options)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(fetched.len(), 1);
+        let fetched = &fetched[0];
+        assert_eq!(fetched.block, block);
+
+        // Results follow the order of the block's transactions.
+        let hashes: Vec<_> = block.all_transaction_hashes().collect();
+        let fetched_hashes: Vec<_> = fetched
+            .transactions
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(Transaction::hash)
+            .collect();
+        assert_eq!(fetched_hashes, hashes);
+        let expected_results: Vec<ExecutionResult> = hashes
+            .iter()
+            .map(|hash| {
+                let index = transactions
+                    .iter()
+                    .position(|transaction| transaction.hash() == *hash)
+                    .unwrap();
+                execution_results[index].clone()
+            })
+            .collect();
+        assert_eq!(fetched.execution_results, Some(expected_results));
+    }
+
+    #[tokio::test]
+    async fn test_blocks_retry_transient_failures() {
+        let rng = &mut TestRng::new();
+        let failures = Arc::new(AtomicUsize::new(2));
+        let failures_remaining = failures.clone();
+        let request = Command::Get(block_header_request(1));
+        let mut proxy = with_available_range(MockProxy::new(), 0, 2).with_error(
+            // Fails the first two requests for the block at height 1.
+            RequestMatcher::custom(move |req| {
+                req == &request
+                    && failures_remaining
+                        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                            count.checked_sub(1)
+                        })
+                        .is_ok()
+            }),
+            || BinaryError::from(TransportError::MessageTimeout),
+        );
+        for height in 0..3 {
+            proxy = with_block(proxy, &get_block(rng, height, &[]));
+        }
+        let client = Client::new(Arc::new(proxy));
+
+        assert_eq!(fetch_heights(&client, ..).await, vec![0, 1, 2]);
+        assert_eq!(failures.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_blocks_yield_error_once_retries_exhausted() {
+        let rng = &mut TestRng::new();
+        let mut proxy = with_available_range(MockProxy::new(), 0, 2);
+        // Node lacks the block at height 1 despite its available range.
+        for height in [0, 2] {
+            proxy = with_block(proxy, &get_block(rng, height, &[]));
+        }
+        let proxy = Arc::new(proxy);
+        let client = Client::new(proxy.clone());
+
+        let options = BlockRangeOptions {
+            max_retries: 2,
+            ..options()
+        };
+        let results: Vec<_> = client.blocks(.., options).collect().await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().block.height(), 0);
+        assert!(matches!(
+            &results[1],
+            Err(BinaryError::Protocol(ProtocolError::MissingRecord {
+                record_id: RecordId::BlockHeader,
+                ..
+            }))
+        ));
+        assert_eq!(results[2].as_ref().unwrap().block.height(), 2);
+
+        let header_requests = proxy
+            .requests()
+            .into_iter()
+            .filter(|req| matches!(req, Command::Get(req) if *req == block_header_request(1)))
+            .count();
+        assert_eq!(header_requests, 3);
+    }
+
+    #[tokio::test]
+    async fn test_blocks_yield_undecodable_response_without_retrying() {
+        let rng = &mut TestRng::new();
+        let mut proxy = with_available_range(MockProxy::new(), 1, 1)
+            // Node answers with a response of the wrong type.
+            .with_value(block_header_request(1), Transaction::random(rng));
+        proxy = with_block(proxy, &get_block(rng, 1, &[]));
+        let proxy = Arc::new(proxy);
+        let client = Client::new(proxy.clone());
+
+        let results: Vec<_> = client.blocks(.., options()).collect().await;
+        assert!(matches!(
+            results.as_slice(),
+            [Err(BinaryError::Protocol(
+                ProtocolError::UnexpectedResponseType { .. }
+            ))]
+        ));
+        let header_requests = proxy
+            .requests()
+            .into_iter()
+            .filter(|req| matches!(req, Command::Get(req) if *req == block_header_request(1)))
+            .count();
+        assert_eq!(header_requests, 1);
+    }
+}
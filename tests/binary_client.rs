@@ -0,0 +1,512 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::{ProtocolError, TransportError},
+        proxy::{ExponentialBackoffConfig, MaxAttempts, MockProxy, ProxyConfig, RemoteProxy},
+        BinaryError, Client,
+    };
+    use casper_types::{
+        bytesrepr::{Bytes, FromBytes, ToBytes},
+        testing::TestRng,
+        BlockHeader, BlockIdentifier, TestBlockBuilder,
+    };
+    use futures::{SinkExt, StreamExt};
+    use l1_binary_port::{
+        BinaryMessage, BinaryMessageCodec, BinaryResponse, BinaryResponseAndRequest, Command,
+        CommandHeader, CommandTag, GetRequest, InformationRequest, InformationRequestTag,
+    };
+    use std::{
+        convert::TryFrom,
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+    use tokio::net::TcpListener;
+    use tokio_util::codec::Framed;
+
+    const MAX_MESSAGE_SIZE_BYTES: u32 = 4 * 1024 * 1024;
+
+    fn get_config(address: SocketAddr) -> ProxyConfig {
+        ProxyConfig {
+            address,
+            max_message_size_bytes: MAX_MESSAGE_SIZE_BYTES,
+            message_timeout_secs: 5,
+            client_access_timeout_secs: 5,
+            request_limit: 1,
+            request_buffer_size: 1,
+            exponential_backoff: ExponentialBackoffConfig {
+                initial_delay_ms: 10,
+                max_delay_ms: 100,
+                coefficient: 2,
+                max_attempts: MaxAttempts::Finite(1),
+            },
+        }
+    }
+
+    /// Builds the response of a node stand-in to a block header request.
+    fn respond(header: &BlockHeader, message: &BinaryMessage, id_offset: u16) -> BinaryMessage {
+        let (request_header, remainder) = CommandHeader::from_bytes(message.payload()).unwrap();
+        let tag = CommandTag::try_from(request_header.type_tag())
+            .unwrap_or_else(|_| panic!("Invalid command tag"));
+        let command = Command::try_from((tag, remainder)).unwrap();
+        match &command {
+            Command::Get(GetRequest::Information { info_type_tag, .. }) => {
+                assert_eq!(*info_type_tag, InformationRequestTag::BlockHeader as u16);
+            }
+            _ => panic!("Unexpected request: {:?}", command),
+        }
+
+        let echoed_header = CommandHeader::new(command.tag(), request_header.id() + id_offset);
+        let mut echoed_request = echoed_header.to_bytes().unwrap();
+        echoed_request.extend_from_slice(remainder);
+        let response = BinaryResponseAndRequest::new(
+            BinaryResponse::from_value(header.clone()),
+            Bytes::from(echoed_request),
+        );
+        BinaryMessage::new(response.to_bytes().unwrap())
+    }
+
+    /// Starts a node stand-in answering block header requests, optionally corrupting the echoed id.
+    async fn start_node(header: BlockHeader, id_offset: u16) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut framed = Framed::new(socket, BinaryMessageCodec::new(MAX_MESSAGE_SIZE_BYTES));
+            while let Some(Ok(message)) = framed.next().await {
+                let response = respond(&header, &message, id_offset);
+                framed.send(response).await.unwrap();
+            }
+        });
+
+        address
+    }
+
+    /// Starts a node stand-in reading `batch_size` requests before answering them, then closing
+    /// the connection.
+    async fn start_batching_node(header: BlockHeader, batch_size: usize) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut framed =
+                    Framed::new(socket, BinaryMessageCodec::new(MAX_MESSAGE_SIZE_BYTES));
+                let mut requests = Vec::new();
+                while requests.len() < batch_size {
+                    match framed.next().await {
+                        Some(Ok(message)) => requests.push(message),
+                        _ => break,
+                    }
+                }
+                for message in &requests {
+                    framed.send(respond(&header, message, 0)).await.unwrap();
+                }
+            }
+        });
+
+        address
+    }
+
+    /// Starts a node stand-in which accepts connections but never answers.
+    async fn start_silent_node() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                connections.push(socket);
+            }
+        });
+
+        address
+    }
+
+    /// Starts a node stand-in answering requests for block headers by height, reading
+    /// `batch_size` requests before answering them in reverse order, each after the delay given
+    /// by `answer`, or never if `None`.  Returns the count of accepted connections.
+    async fn start_height_node(
+        headers: Vec<BlockHeader>,
+        batch_size: usize,
+        answer: fn(u64) -> Option<Duration>,
+    ) -> (SocketAddr, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let connection_count = connections.clone();
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                connection_count.fetch_add(1, Ordering::SeqCst);
+                let headers = headers.clone();
+                tokio::spawn(async move {
+                    let mut framed =
+                        Framed::new(socket, BinaryMessageCodec::new(MAX_MESSAGE_SIZE_BYTES));
+                    loop {
+                        let mut requests = Vec::new();
+                        while requests.len() < batch_size {
+                            match framed.next().await {
+                                Some(Ok(message)) => requests.push(message),
+                                _ => return,
+                            }
+                        }
+                        for message in requests.iter().rev() {
+                            let height = requested_height(message);
+                            let Some(delay) = answer(height) else {
+                                continue;
+                            };
+                            tokio::time::sleep(delay).await;
+                            let header = &headers[height as usize];
+                            framed.send(respond(header, message, 0)).await.unwrap();
+                        }
+                    }
+                });
+            }
+        });
+
+        (address, connections)
+    }
+
+    fn requested_height(message: &BinaryMessage) -> u64 {
+        let (request_header, remainder) = CommandHeader::from_bytes(message.payload()).unwrap();
+        let tag = CommandTag::try_from(request_header.type_tag())
+            .unwrap_or_else(|_| panic!("Invalid command tag"));
+        let Command::Get(GetRequest::Information { info_type_tag, key }) =
+            Command::try_from((tag, remainder)).unwrap()
+        else {
+            panic!("Unexpected request");
+        };
+        let tag = InformationRequestTag::try_from(info_type_tag).unwrap();
+        match InformationRequest::try_from((tag, key.as_slice())).unwrap() {
+            InformationRequest::BlockHeader(Some(BlockIdentifier::Height(height))) => height,
+            request => panic!("Unexpected request: {:?}", request),
+        }
+    }
+
+    fn get_block_header(rng: &mut TestRng) -> BlockHeader {
+        BlockHeader::from(TestBlockBuilder::new().build(rng).take_header())
+    }
+
+    fn get_block_headers(rng: &mut TestRng, count: u64) -> Vec<BlockHeader> {
+        (0..count)
+            .map(|height| {
+                let block = TestBlockBuilder::new().height(height).build(rng);
+                BlockHeader::from(block.take_header())
+            })
+            .collect()
+    }
+
+    async fn get_block_header_at(client: &Client, height: u64) -> Result<BlockHeader, BinaryError> {
+        let block_id = Some(BlockIdentifier::Height(height));
+        let header = client
+            .get()
+            .information()
+            .get_block_header(block_id)
+            .await?;
+        Ok(header.expect("node should answer with a header"))
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_get_block_header() {
+        let rng = &mut TestRng::new();
+        let header = get_block_header(rng);
+        let address = start_node(header.clone(), 0).await;
+        let client = Client::new(Arc::new(RemoteProxy::new(get_config(address))));
+
+        for _ in 0..2 {
+            let result = client
+                .get()
+                .information()
+                .get_block_header(Some(BlockIdentifier::Height(header.height())))
+                .await
+                .unwrap();
+            assert_eq!(result, Some(header.clone()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_request_id_mismatch() {
+        let rng = &mut TestRng::new();
+        let address = start_node(get_block_header(rng), 1).await;
+        let client = Client::new(Arc::new(RemoteProxy::new(get_config(address))));
+
+        let result = client.get().information().get_block_header(None).await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Protocol(ProtocolError::RequestIdMismatch {
+                expected: 0,
+                got: 1
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_connection_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let client = Client::new(Arc::new(RemoteProxy::new(get_config(address))));
+
+        let result = client.get().information().get_block_header(None).await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Transport(TransportError::ConnectionError(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_reconnects_after_connection_closed() {
+        let rng = &mut TestRng::new();
+        let header = get_block_header(rng);
+        let address = start_batching_node(header.clone(), 1).await;
+        let client = Client::new(Arc::new(RemoteProxy::new(get_config(address))));
+
+        for _ in 0..3 {
+            let result = client.get().information().get_block_header(None).await;
+            assert_eq!(result.unwrap(), Some(header.clone()));
+            // Allow the proxy to observe the node closing the connection.
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_pipelines_up_to_request_limit() {
+        let rng = &mut TestRng::new();
+        let header = get_block_header(rng);
+        let address = start_batching_node(header.clone(), 2).await;
+        let mut config = get_config(address);
+        config.request_limit = 2;
+        config.request_buffer_size = 2;
+        let client = Client::new(Arc::new(RemoteProxy::new(config)));
+
+        let (first, second) = tokio::join!(
+            client.get().information().get_block_header(None),
+            client.get().information().get_block_header(None),
+        );
+        assert_eq!(first.unwrap(), Some(header.clone()));
+        assert_eq!(second.unwrap(), Some(header));
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_message_timeout() {
+        let address = start_silent_node().await;
+        let mut config = get_config(address);
+        config.message_timeout_secs = 1;
+        let client = Client::new(Arc::new(RemoteProxy::new(config)));
+
+        let result = client.get().information().get_block_header(None).await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Transport(TransportError::MessageTimeout))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_client_access_timeout() {
+        let address = start_silent_node().await;
+        let mut config = get_config(address);
+        config.client_access_timeout_secs = 1;
+        let client = Arc::new(Client::new(Arc::new(RemoteProxy::new(config))));
+
+        // First request is in flight and second is buffered, hence third cannot be queued.
+        for _ in 0..2 {
+            let client = client.clone();
+            tokio::spawn(async move { client.get().information().get_block_header(None).await });
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        let result = client.get().information().get_block_header(None).await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Transport(TransportError::ClientAccessTimeout))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_retries_connection() {
+        let rng = &mut TestRng::new();
+        let header = get_block_header(rng);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let mut config = get_config(address);
+        config.exponential_backoff.max_attempts = MaxAttempts::Infinite;
+        let client = Client::new(Arc::new(RemoteProxy::new(config)));
+
+        // Node becomes available whilst the proxy is backing off.
+        let node = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let listener = TcpListener::bind(address).await.unwrap();
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut framed = Framed::new(socket, BinaryMessageCodec::new(MAX_MESSAGE_SIZE_BYTES));
+            let message = framed.next().await.unwrap().unwrap();
+            framed.send(respond(&header, &message, 0)).await.unwrap();
+            header
+        });
+
+        let result = client.get().information().get_block_header(None).await;
+        assert_eq!(result.unwrap(), Some(node.await.unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_times_out_whilst_reconnecting() {
+        let rng = &mut TestRng::new();
+        let header = get_block_header(rng);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let mut config = get_config(address);
+        config.message_timeout_secs = 1;
+        config.request_buffer_size = 2;
+        config.exponential_backoff.max_attempts = MaxAttempts::Infinite;
+        let client = Client::new(Arc::new(RemoteProxy::new(config)));
+
+        // Requests queued behind the connection attempt time out, rather than await the node.
+        let (first, second) = tokio::time::timeout(
+            Duration::from_secs(3),
+            futures::future::join(
+                client.get().information().get_block_header(None),
+                client.get().information().get_block_header(None),
+            ),
+        )
+        .await
+        .expect("requests should time out whilst the node is unreachable");
+        for result in [first, second] {
+            assert!(matches!(
+                result,
+                Err(BinaryError::Transport(TransportError::MessageTimeout))
+            ));
+        }
+
+        // Proxy still connects once the node becomes available.
+        let node = tokio::spawn(async move {
+            let listener = TcpListener::bind(address).await.unwrap();
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut framed = Framed::new(socket, BinaryMessageCodec::new(MAX_MESSAGE_SIZE_BYTES));
+            let message = framed.next().await.unwrap().unwrap();
+            framed.send(respond(&header, &message, 0)).await.unwrap();
+            header
+        });
+        let result = client.get().information().get_block_header(None).await;
+        assert_eq!(result.unwrap(), Some(node.await.unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_demultiplexes_out_of_order_responses() {
+        let rng = &mut TestRng::new();
+        let headers = get_block_headers(rng, 3);
+        let (address, _) = start_height_node(headers.clone(), 3, |_| Some(Duration::ZERO)).await;
+        let mut config = get_config(address);
+        config.request_limit = 3;
+        config.request_buffer_size = 3;
+        let client = Client::new(Arc::new(RemoteProxy::new(config)));
+
+        let (first, second, third) = tokio::join!(
+            get_block_header_at(&client, 0),
+            get_block_header_at(&client, 1),
+            get_block_header_at(&client, 2),
+        );
+        assert_eq!(first.unwrap(), headers[0]);
+        assert_eq!(second.unwrap(), headers[1]);
+        assert_eq!(third.unwrap(), headers[2]);
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_request_timeout_spares_other_requests() {
+        let rng = &mut TestRng::new();
+        let headers = get_block_headers(rng, 2);
+        let (address, connections) = start_height_node(headers.clone(), 1, |height| {
+            (height != 0).then_some(Duration::ZERO)
+        })
+        .await;
+        let mut config = get_config(address);
+        config.message_timeout_secs = 1;
+        config.request_limit = 2;
+        config.request_buffer_size = 2;
+        let client = Client::new(Arc::new(RemoteProxy::new(config)));
+
+        let (unanswered, answered) = tokio::join!(
+            get_block_header_at(&client, 0),
+            get_block_header_at(&client, 1),
+        );
+        assert!(matches!(
+            unanswered,
+            Err(BinaryError::Transport(TransportError::MessageTimeout))
+        ));
+        assert_eq!(answered.unwrap(), headers[1]);
+
+        // Connection is retained as the node answers other requests.
+        assert_eq!(get_block_header_at(&client, 1).await.unwrap(), headers[1]);
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_discards_response_to_cancelled_request() {
+        let rng = &mut TestRng::new();
+        let headers = get_block_headers(rng, 2);
+        let (address, connections) = start_height_node(headers.clone(), 1, |height| {
+            Some(Duration::from_millis(if height == 0 { 300 } else { 0 }))
+        })
+        .await;
+        let client = Client::new(Arc::new(RemoteProxy::new(get_config(address))));
+
+        let cancelled =
+            tokio::time::timeout(Duration::from_millis(100), get_block_header_at(&client, 0));
+        assert!(cancelled.await.is_err());
+        assert_eq!(get_block_header_at(&client, 1).await.unwrap(), headers[1]);
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_remote_proxy_reuses_slots_of_cancelled_requests() {
+        let rng = &mut TestRng::new();
+        let headers = get_block_headers(rng, 3);
+        let (address, connections) = start_height_node(headers.clone(), 1, |height| {
+            (height != 0).then_some(Duration::ZERO)
+        })
+        .await;
+        let mut config = get_config(address);
+        config.request_limit = 2;
+        config.request_buffer_size = 2;
+        let client = Client::new(Arc::new(RemoteProxy::new(config)));
+
+        // Callers give up on requests the node never answers, which occupy every slot.
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(100),
+            futures::future::join(
+                get_block_header_at(&client, 0),
+                get_block_header_at(&client, 0),
+            ),
+        );
+        assert!(cancelled.await.is_err());
+
+        // Their slots are released well before the requests would have timed out.
+        let (first, second) = tokio::time::timeout(
+            Duration::from_secs(2),
+            futures::future::join(
+                get_block_header_at(&client, 1),
+                get_block_header_at(&client, 2),
+            ),
+        )
+        .await
+        .expect("slots of cancelled requests should be reused");
+        assert_eq!(first.unwrap(), headers[1]);
+        assert_eq!(second.unwrap(), headers[2]);
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mock_proxy_get_block_header() {
+        let client = Client::new(Arc::new(MockProxy::new()));
+
+        let result = client.get().information().get_block_header(None).await;
+        assert!(matches!(result, Ok(None)));
+    }
+}
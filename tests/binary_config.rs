@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::ConfigError,
+        proxy::{ExponentialBackoffConfig, MaxAttempts, ProxyConfig},
+    };
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_default_config() {
+        let config = ProxyConfig::default();
+        assert_eq!(config.address, "127.0.0.1:28104".parse().unwrap());
+        assert_eq!(config.max_message_size_bytes, 4 * 1024 * 1024);
+        assert_eq!(config.request_limit, 3);
+        assert_eq!(
+            config.exponential_backoff.max_attempts,
+            MaxAttempts::Infinite
+        );
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_toml_layered_over_defaults() {
+        let config = ProxyConfig::from_toml_str(
+            r#"
+            address = "10.0.0.1:7779"
+            request_limit = 10
+
+            [exponential_backoff]
+            max_attempts = 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            ProxyConfig {
+                address: "10.0.0.1:7779".parse().unwrap(),
+                request_limit: 10,
+                exponential_backoff: ExponentialBackoffConfig {
+                    max_attempts: MaxAttempts::Finite(5),
+                    ..ExponentialBackoffConfig::default()
+                },
+                ..ProxyConfig::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_toml_max_attempts() {
+        let config = ProxyConfig::from_toml_str(
+            r#"
+            [exponential_backoff]
+            max_attempts = "infinite"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.exponential_backoff.max_attempts,
+            MaxAttempts::Infinite
+        );
+
+        for max_attempts in ["-1", "\"forever\"", "{ Finite = 5 }"] {
+            let result = ProxyConfig::from_toml_str(&format!(
+                "[exponential_backoff]\nmax_attempts = {max_attempts}"
+            ));
+            assert!(matches!(result, Err(ConfigError::ParseError(_))));
+        }
+    }
+
+    #[test]
+    fn test_toml_unknown_field() {
+        let result = ProxyConfig::from_toml_str("request_limits = 10");
+        assert!(matches!(result, Err(ConfigError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_toml_file() {
+        let path = std::env::temp_dir().join(format!("proxy-config-{}.toml", std::process::id()));
+        std::fs::write(&path, "message_timeout_secs = 5").unwrap();
+        let result = ProxyConfig::load(Some(&path));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap().message_timeout_secs, 5);
+
+        assert!(matches!(
+            ProxyConfig::from_toml_file(&path),
+            Err(ConfigError::ReadError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let config = ProxyConfig::from_toml_str("request_limit = 10")
+            .unwrap()
+            .with_env_overrides(env(&[
+                ("CASPER_BINARY_PORT_REQUEST_LIMIT", "20"),
+                ("CASPER_BINARY_PORT_ADDRESS", "10.0.0.2:7779"),
+                ("CASPER_BINARY_PORT_EXPONENTIAL_BACKOFF_MAX_ATTEMPTS", "3"),
+                ("PATH", "/usr/bin"),
+            ]))
+            .unwrap();
+        assert_eq!(config.request_limit, 20);
+        assert_eq!(config.address, "10.0.0.2:7779".parse().unwrap());
+        assert_eq!(
+            config.exponential_backoff.max_attempts,
+            MaxAttempts::Finite(3)
+        );
+
+        let config = config
+            .with_env_overrides(env(&[(
+                "CASPER_BINARY_PORT_EXPONENTIAL_BACKOFF_MAX_ATTEMPTS",
+                "Infinite",
+            )]))
+            .unwrap();
+        assert_eq!(
+            config.exponential_backoff.max_attempts,
+            MaxAttempts::Infinite
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_ignores_non_unicode_env_vars() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let name = format!("PROXY_CONFIG_NON_UNICODE_{}", std::process::id());
+        std::env::set_var(&name, OsStr::from_bytes(&[0x66, 0x6f, 0x80]));
+        let result = ProxyConfig::load(None);
+        std::env::remove_var(&name);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_env_errors() {
+        let result = ProxyConfig::default()
+            .with_env_overrides(env(&[("CASPER_BINARY_PORT_REQUEST_LIMIT", "many")]));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Invalid value for environment variable CASPER_BINARY_PORT_REQUEST_LIMIT: \"many\""
+        );
+
+        let result = ProxyConfig::default()
+            .with_env_overrides(env(&[("CASPER_BINARY_PORT_REQUEST_LIMITS", "1")]));
+        assert!(matches!(result, Err(ConfigError::UnknownEnvVar(name))
+            if name == "CASPER_BINARY_PORT_REQUEST_LIMITS"));
+    }
+
+    #[test]
+    fn test_validation() {
+        let config = ProxyConfig {
+            request_limit: 0,
+            ..ProxyConfig::default()
+        };
+        assert_eq!(
+            config.validate().unwrap_err().to_string(),
+            "Invalid config: request_limit must be greater than zero"
+        );
+
+        let config = ProxyConfig {
+            exponential_backoff: ExponentialBackoffConfig {
+                initial_delay_ms: 10,
+                max_delay_ms: 5,
+                ..ExponentialBackoffConfig::default()
+            },
+            ..ProxyConfig::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidValue {
+                field: "exponential_backoff.initial_delay_ms",
+                ..
+            })
+        ));
+
+        let config = ProxyConfig {
+            exponential_backoff: ExponentialBackoffConfig {
+                max_attempts: MaxAttempts::Finite(0),
+                ..ExponentialBackoffConfig::default()
+            },
+            ..ProxyConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{error::NodeError, types::ErrorCode, BinaryError};
+
+    #[test]
+    fn test_node_error_preserves_error_code() {
+        for error_code in 1..=u8::MAX as u16 {
+            let node_error = NodeError::from_error_code(error_code);
+            assert_eq!(node_error.error_code(), error_code);
+            if ErrorCode::try_from(error_code).is_err() {
+                assert_eq!(node_error, NodeError::Unknown(error_code));
+            }
+        }
+    }
+
+    #[test]
+    fn test_node_error_categories() {
+        let cases = [
+            (
+                ErrorCode::NotFound,
+                NodeError::NotFound(ErrorCode::NotFound),
+            ),
+            (
+                ErrorCode::UnsupportedRequest,
+                NodeError::UnsupportedRequest(ErrorCode::UnsupportedRequest),
+            ),
+            (ErrorCode::WasmPreprocessing, NodeError::WasmPreprocessing),
+            (
+                ErrorCode::GasPriceToleranceTooLow,
+                NodeError::GasPriceTooHigh(ErrorCode::GasPriceToleranceTooLow),
+            ),
+            (
+                ErrorCode::InvalidDeployExcessiveTimeToLive,
+                NodeError::InvalidTransaction(ErrorCode::InvalidDeployExcessiveTimeToLive),
+            ),
+            (
+                ErrorCode::InsufficientBalance,
+                NodeError::TransactionNotExecutable(ErrorCode::InsufficientBalance),
+            ),
+            (ErrorCode::RequestThrottled, NodeError::Throttled),
+        ];
+        for (error_code, expected) in cases {
+            assert_eq!(NodeError::from(error_code), expected);
+        }
+    }
+
+    #[test]
+    fn test_binary_error_display() {
+        let error = BinaryError::from_error_code(ErrorCode::InvalidTransactionChainName as u16);
+        assert_eq!(
+            error.to_string(),
+            "Node error: invalid transaction: the transaction sent to the network had an invalid \
+             chain name"
+        );
+        assert!(matches!(
+            BinaryError::from_error_code(u16::MAX),
+            BinaryError::Node(NodeError::Unknown(u16::MAX))
+        ));
+    }
+}
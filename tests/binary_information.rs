@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::ProtocolError,
+        proxy::MockProxy,
+        types::{ConsensusStatus, EraIdentifier, RewardResponse},
+        BinaryError, Client,
+    };
+    use casper_types::{
+        bytesrepr::Bytes, system::auction::DelegatorKind, testing::TestRng, AvailableBlockRange,
+        BlockHash, BlockHeader, ChainspecRawBytes, Digest, EraId, PublicKey, TestBlockBuilder,
+        Timestamp, U512,
+    };
+    use l1_binary_port::{
+        GetRequest, InformationRequest, LastProgress, NetworkName, ReactorStateName, ResponseType,
+        Uptime,
+    };
+    use std::{sync::Arc, time::Duration};
+
+    fn information_request(req: InformationRequest) -> GetRequest {
+        req.try_into().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_node_information() {
+        let last_progress = Timestamp::now();
+        let proxy = MockProxy::new()
+            .with_value(
+                information_request(InformationRequest::Uptime),
+                Uptime::new(42),
+            )
+            .with_value(
+                information_request(InformationRequest::LastProgress),
+                LastProgress::new(last_progress),
+            )
+            .with_value(
+                information_request(InformationRequest::ReactorState),
+                ReactorStateName::new("Validate"),
+            )
+            .with_value(
+                information_request(InformationRequest::NetworkName),
+                NetworkName::new("casper-test"),
+            )
+            .with_value(
+                information_request(InformationRequest::AvailableBlockRange),
+                AvailableBlockRange::new(1, 10),
+            )
+            .with_value(
+                information_request(InformationRequest::ChainspecRawBytes),
+                ChainspecRawBytes::new(Bytes::from(vec![1, 2, 3]), None, None),
+            );
+        let client = Client::new(Arc::new(proxy));
+
+        let information = client.get().information();
+        assert_eq!(
+            information.get_uptime().await.unwrap(),
+            Duration::from_secs(42)
+        );
+        assert_eq!(
+            information.get_last_progress().await.unwrap(),
+            last_progress
+        );
+        assert_eq!(information.get_reactor_state().await.unwrap(), "Validate");
+        assert_eq!(information.get_network_name().await.unwrap(), "casper-test");
+        assert_eq!(
+            information.get_available_block_range().await.unwrap(),
+            AvailableBlockRange::new(1, 10)
+        );
+        assert_eq!(
+            information.get_chainspec_raw_bytes().await.unwrap(),
+            ChainspecRawBytes::new(Bytes::from(vec![1, 2, 3]), None, None)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_optional_information() {
+        let rng = &mut TestRng::new();
+        let header = BlockHeader::from(
+            TestBlockBuilder::new()
+                .switch_block(true)
+                .build(rng)
+                .take_header(),
+        );
+        let public_key = PublicKey::random(rng);
+        let proxy = MockProxy::new()
+            .with_value(
+                information_request(InformationRequest::LatestSwitchBlockHeader),
+                header.clone(),
+            )
+            .with_value(
+                information_request(InformationRequest::ConsensusStatus),
+                ConsensusStatus::new(public_key.clone(), None),
+            );
+        let client = Client::new(Arc::new(proxy));
+
+        let information = client.get().information();
+        assert_eq!(
+            information.get_latest_switch_block_header().await.unwrap(),
+            Some(header)
+        );
+        assert_eq!(
+            information.get_consensus_status().await.unwrap(),
+            Some(ConsensusStatus::new(public_key, None))
+        );
+        assert!(matches!(information.get_next_upgrade().await, Ok(None)));
+    }
+
+    #[tokio::test]
+    async fn test_get_required_information_not_found() {
+        let client = Client::new(Arc::new(MockProxy::new()));
+
+        let result = client.get().information().get_network_name().await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Protocol(
+                ProtocolError::UnexpectedResponseType {
+                    expected: ResponseType::NetworkName,
+                    got: None
+                }
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_rewards() {
+        let rng = &mut TestRng::new();
+        let validator = PublicKey::random(rng);
+        let delegator = DelegatorKind::PublicKey(PublicKey::random(rng));
+        let era_identifier = Some(EraIdentifier::Era(EraId::new(3)));
+        let reward = |amount: u64| {
+            RewardResponse::new(
+                U512::from(amount),
+                EraId::new(3),
+                10,
+                BlockHash::new(Digest::hash([1; 8])),
+            )
+        };
+        let proxy = MockProxy::new()
+            .with_value(
+                information_request(InformationRequest::Reward {
+                    era_identifier: era_identifier.clone(),
+                    validator: Box::new(validator.clone()),
+                    delegator: None,
+                }),
+                reward(100),
+            )
+            .with_value(
+                information_request(InformationRequest::Reward {
+                    era_identifier: era_identifier.clone(),
+                    validator: Box::new(validator.clone()),
+                    delegator: Some(Box::new(delegator.clone())),
+                }),
+                reward(5),
+            );
+        let client = Client::new(Arc::new(proxy));
+
+        let information = client.get().information();
+        assert_eq!(
+            information
+                .get_validator_reward(era_identifier.clone(), validator.clone())
+                .await
+                .unwrap()
+                .map(|reward| reward.amount()),
+            Some(U512::from(100))
+        );
+        assert_eq!(
+            information
+                .get_delegator_reward(era_identifier, validator.clone(), delegator)
+                .await
+                .unwrap()
+                .map(|reward| reward.amount()),
+            Some(U512::from(5))
+        );
+        assert!(matches!(
+            information.get_validator_reward(None, validator).await,
+            Ok(None)
+        ));
+    }
+}
@@ -0,0 +1,418 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::{
+        api::node::binary::{
+            error::LightClientError,
+            light_client::{LightClientConfig, TrustedState},
+            proxy::MockProxy,
+            Client, LightClient,
+        },
+        crypto::ChainError,
+    };
+    use casper_types::{
+        bytesrepr::ToBytes, testing::TestRng, Block, BlockHash, BlockHeader, BlockIdentifier,
+        BlockSignatures, BlockSignaturesV1, BlockSignaturesV2, BlockV1, BlockV2, ChainNameDigest,
+        Digest, EraEndV1, EraEndV2, EraId, EraReport, FinalitySignatureV1, FinalitySignatureV2,
+        ProtocolVersion, PublicKey, RewardedSignatures, SecretKey, Timestamp, U512,
+    };
+    use l1_binary_port::{BinaryResponseAndRequest, GetRequest, InformationRequest, RecordId};
+    use std::{collections::BTreeMap, sync::Arc};
+
+    const CHAIN_NAME: &str = "casper-test";
+
+    fn record_request(record_id: RecordId, key: impl ToBytes) -> GetRequest {
+        GetRequest::Record {
+            record_type_tag: record_id.into(),
+            key: key.to_bytes().unwrap(),
+        }
+    }
+
+    fn block_header_request(block_id: Option<BlockIdentifier>) -> GetRequest {
+        InformationRequest::BlockHeader(block_id)
+            .try_into()
+            .unwrap()
+    }
+
+    /// Validators signing a block.
+    #[derive(Clone, Copy)]
+    enum Signers {
+        /// First validators of the block's era.
+        First(usize),
+        /// Validators of the era preceding the block's.
+        PreviousEra,
+    }
+
+    /// Validators rotated into each era.
+    const ROTATION: usize = 3;
+
+    /// Validators of each era.
+    const VALIDATORS: usize = 4;
+
+    /// Chain of blocks, each signed by all validators of its era unless specified otherwise.
+    ///
+    /// Validators of era `n` are `VALIDATORS` keys from `n * ROTATION`, those of consecutive eras
+    /// overlapping by one.
+    struct TestChain {
+        keys: Vec<SecretKey>,
+        blocks: Vec<Block>,
+        signers: BTreeMap<u64, Signers>,
+    }
+
+    impl TestChain {
+        /// Builds a chain whose blocks are switch blocks as per `switch_blocks`, the first block
+        /// being one.
+        fn new(rng: &mut TestRng, switch_blocks: &[bool]) -> Self {
+            TestChain::new_upgraded(rng, switch_blocks, 0)
+        }
+
+        /// Builds a chain as per `new`, whose blocks below `upgrade_height` are legacy blocks.
+        fn new_upgraded(rng: &mut TestRng, switch_blocks: &[bool], upgrade_height: usize) -> Self {
+            let eras = 2 + switch_blocks.iter().filter(|is_switch| **is_switch).count();
+            let keys = (0..(eras - 1) * ROTATION + VALIDATORS)
+                .map(|_| SecretKey::random(rng))
+                .collect();
+            let mut chain = TestChain {
+                keys,
+                blocks: vec![],
+                signers: BTreeMap::new(),
+            };
+            let mut last_switch_block_hash = None;
+            for (height, is_switch) in [true].iter().chain(switch_blocks).enumerate() {
+                let (parent_hash, era_id) = match chain.blocks.last() {
+                    Some(parent) => (*parent.hash(), parent.clone_header().next_block_era_id()),
+                    None => (BlockHash::default(), EraId::new(0)),
+                };
+                let validators = chain.validators(era_id);
+                let proposer = PublicKey::from(&validators[height % validators.len()]);
+                let next_era_weights = is_switch.then(|| chain.weights(era_id.successor()));
+                let block = if height < upgrade_height {
+                    let era_end = next_era_weights
+                        .map(|weights| EraEndV1::new(EraReport::default(), weights));
+                    Block::from(BlockV1::new(
+                        parent_hash,
+                        Digest::random(rng),
+                        Digest::random(rng),
+                        false,
+                        era_end,
+                        Timestamp::now(),
+                        era_id,
+                        height as u64,
+                        ProtocolVersion::V1_0_0,
+                        proposer,
+                        vec![],
+                        vec![],
+                    ))
+                } else {
+                    let era_end = next_era_weights
+                        .map(|weights| EraEndV2::new(vec![], vec![], weights, BTreeMap::new(), 1));
+                    Block::from(BlockV2::new(
+                        parent_hash,
+                        Digest::random(rng),
+                        Digest::random(rng),
+                        false,
+                        era_end,
+                        Timestamp::now(),
+                        era_id,
+                        height as u64,
+                        ProtocolVersion::V2_0_0,
+                        proposer,
+                        BTreeMap::new(),
+                        RewardedSignatures::default(),
+                        1,
+                        last_switch_block_hash,
+                    ))
+                };
+                if *is_switch {
+                    last_switch_block_hash = Some(*block.hash());
+                }
+                chain.blocks.push(block);
+            }
+            chain
+        }
+
+        fn validators(&self, era_id: EraId) -> &[SecretKey] {
+            let first = era_id.value() as usize * ROTATION;
+            &self.keys[first..first + VALIDATORS]
+        }
+
+        /// Weights of an era's validators, differing from one validator to the next.
+        fn weights(&self, era_id: EraId) -> BTreeMap<PublicKey, U512> {
+            self.validators(era_id)
+                .iter()
+                .enumerate()
+                .map(|(index, secret_key)| {
+                    (PublicKey::from(secret_key), U512::from(100 + 10 * index))
+                })
+                .collect()
+        }
+
+        /// Limits signatures of the block at a height to those of the given validators.
+        fn with_signers(mut self, height: u64, signers: Signers) -> Self {
+            self.signers.insert(height, signers);
+            self
+        }
+
+        fn header(&self, height: u64) -> BlockHeader {
+            self.blocks[height as usize].clone_header()
+        }
+
+        fn signatures(&self, block: &Block) -> BlockSignatures {
+            let signers = match self.signers.get(&block.height()) {
+                None => self.validators(block.era_id()),
+                Some(Signers::First(count)) => &self.validators(block.era_id())[..*count],
+                Some(Signers::PreviousEra) => {
+                    self.validators(block.era_id().predecessor().unwrap())
+                }
+            };
+            if let Block::V1(block) = block {
+                let mut signatures = BlockSignaturesV1::new(*block.hash(), block.era_id());
+                for secret_key in signers {
+                    let signature =
+                        FinalitySignatureV1::create(*block.hash(), block.era_id(), secret_key);
+                    signatures
+                        .insert_signature(PublicKey::from(secret_key), *signature.signature());
+                }
+                return BlockSignatures::V1(signatures);
+            }
+            let chain_name_hash = ChainNameDigest::from_chain_name(CHAIN_NAME);
+            let mut signatures = BlockSignaturesV2::new(
+                *block.hash(),
+                block.height(),
+                block.era_id(),
+                chain_name_hash,
+            );
+            for secret_key in signers {
+                let signature = FinalitySignatureV2::create(
+                    *block.hash(),
+                    block.height(),
+                    block.era_id(),
+                    chain_name_hash,
+                    secret_key,
+                );
+                signatures.insert_signature(PublicKey::from(secret_key), *signature.signature());
+            }
+            BlockSignatures::V2(signatures)
+        }
+
+        /// Client of a node returning legacy records as bincode-encoded legacy responses.
+        fn client(&self) -> Arc<Client> {
+            let mut proxy = MockProxy::new().with_value(
+                block_header_request(None),
+                self.blocks.last().unwrap().clone_header(),
+            );
+            for block in &self.blocks {
+                let header = block.clone_header();
+                proxy = proxy.with_value(
+                    block_header_request(Some(BlockIdentifier::Height(block.height()))),
+                    header.clone(),
+                );
+                let header_request = record_request(RecordId::BlockHeader, block.hash());
+                let signatures_request = record_request(RecordId::BlockMetadata, block.hash());
+                proxy = match (header, self.signatures(block)) {
+                    (BlockHeader::V1(header), BlockSignatures::V1(signatures)) => proxy
+                        .with_response(
+                            header_request,
+                            BinaryResponseAndRequest::new_legacy_test_response(
+                                RecordId::BlockHeader,
+                                &header,
+                            )
+                            .into(),
+                        )
+                        .with_response(
+                            signatures_request,
+                            BinaryResponseAndRequest::new_legacy_test_response(
+                                RecordId::BlockMetadata,
+                                &signatures,
+                            )
+                            .into(),
+                        ),
+                    (header, signatures) => proxy
+                        .with_value(header_request, header)
+                        .with_value(signatures_request, signatures),
+                };
+            }
+            Arc::new(Client::new(Arc::new(proxy)))
+        }
+    }
+
+    /// Chain of four eras, the first holding only the first block.
+    fn switch_blocks() -> Vec<bool> {
+        vec![
+            false, false, false, true, false, false, false, false, true, false, false, true, false,
+            false,
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_sync_across_eras() {
+        let rng = &mut TestRng::new();
+        let chain = TestChain::new(rng, &switch_blocks());
+        let client = LightClient::new(
+            chain.client(),
+            *chain.blocks[0].hash(),
+            LightClientConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(client.latest_finalized_header(), chain.header(0));
+
+        let tip = chain.header(14);
+        assert_eq!(client.sync().await.unwrap(), tip);
+        let TrustedState {
+            header,
+            era_id,
+            validator_weights,
+        } = client.trusted_state();
+        assert_eq!(header, tip);
+        assert_eq!(era_id, EraId::new(4));
+        assert_eq!(validator_weights, chain.weights(EraId::new(4)));
+        assert_eq!(client.state_root_hash(), *tip.state_root_hash());
+
+        // Already at the node's latest block.
+        assert_eq!(client.sync().await.unwrap(), tip);
+    }
+
+    #[tokio::test]
+    async fn test_sync_across_legacy_eras() {
+        let rng = &mut TestRng::new();
+        // Blocks up to the switch block ending era 1 predate the upgrade to 2.0.
+        let chain = TestChain::new_upgraded(rng, &switch_blocks(), 5);
+        let client = LightClient::new(
+            chain.client(),
+            *chain.blocks[0].hash(),
+            LightClientConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            client.latest_finalized_header(),
+            BlockHeader::V1(_)
+        ));
+
+        assert_eq!(client.sync().await.unwrap(), chain.header(14));
+        assert_eq!(
+            client.trusted_state().validator_weights,
+            chain.weights(EraId::new(4))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_within_era() {
+        let rng = &mut TestRng::new();
+        let chain = TestChain::new(rng, &switch_blocks());
+        let client = LightClient::new(
+            chain.client(),
+            *chain.blocks[6].hash(),
+            LightClientConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let state = client.trusted_state();
+        assert_eq!(state.era_id, EraId::new(2));
+        assert_eq!(
+            Some(&state.validator_weights),
+            chain.header(4).next_era_validator_weights()
+        );
+        assert_eq!(client.sync().await.unwrap(), chain.header(14));
+    }
+
+    #[tokio::test]
+    async fn test_sync_halts_at_insufficiently_signed_switch_block() {
+        let rng = &mut TestRng::new();
+        // A single validator of four signs the switch block ending era 2.
+        let chain = TestChain::new(rng, &switch_blocks()).with_signers(9, Signers::First(1));
+        let client = LightClient::new(
+            chain.client(),
+            *chain.blocks[0].hash(),
+            LightClientConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            client.sync().await,
+            Err(LightClientError::Verification(
+                ChainError::InsufficientSignatureWeight { height: 9, .. }
+            ))
+        ));
+        // Progress up to the switch block ending era 1 is kept.
+        assert_eq!(client.latest_finalized_header(), chain.header(4));
+    }
+
+    #[tokio::test]
+    async fn test_sync_rejects_switch_block_signed_by_previous_era() {
+        let rng = &mut TestRng::new();
+        // Validators of era 1 sign the switch block ending era 2, only one of them remaining.
+        let chain = TestChain::new(rng, &switch_blocks()).with_signers(9, Signers::PreviousEra);
+        let client = LightClient::new(
+            chain.client(),
+            *chain.blocks[0].hash(),
+            LightClientConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            client.sync().await,
+            Err(LightClientError::Verification(
+                ChainError::InsufficientSignatureWeight { height: 9, .. }
+            ))
+        ));
+        assert_eq!(client.latest_finalized_header(), chain.header(4));
+    }
+
+    #[tokio::test]
+    async fn test_sync_steps_back_from_unfinalized_tip() {
+        let rng = &mut TestRng::new();
+        // Signatures of the node's latest block are still being gathered.
+        let chain = TestChain::new(rng, &switch_blocks()).with_signers(14, Signers::First(1));
+        let client = LightClient::new(
+            chain.client(),
+            *chain.blocks[0].hash(),
+            LightClientConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(client.sync().await.unwrap(), chain.header(13));
+        assert_eq!(client.latest_finalized_header(), chain.header(13));
+        // The tip remains unfinalized.
+        assert_eq!(client.sync().await.unwrap(), chain.header(13));
+
+        // No block of the current era is finalized, leaving the switch block ending the last.
+        let chain = TestChain::new(rng, &switch_blocks())
+            .with_signers(13, Signers::First(0))
+            .with_signers(14, Signers::PreviousEra);
+        let client = LightClient::new(
+            chain.client(),
+            *chain.blocks[0].hash(),
+            LightClientConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(client.sync().await.unwrap(), chain.header(12));
+        assert_eq!(client.trusted_state().era_id, EraId::new(4));
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_rejects_forged_trusted_header() {
+        let rng = &mut TestRng::new();
+        let chain = TestChain::new(rng, &switch_blocks());
+        let other = TestChain::new(rng, &switch_blocks());
+        let trusted_hash = *chain.blocks[0].hash();
+        let proxy = MockProxy::new().with_value(
+            record_request(RecordId::BlockHeader, trusted_hash),
+            other.header(0),
+        );
+        let client = Arc::new(Client::new(Arc::new(proxy)));
+
+        assert!(matches!(
+            LightClient::new(client, trusted_hash, LightClientConfig::default()).await,
+            Err(LightClientError::Verification(
+                ChainError::BlockHashMismatch { .. }
+            ))
+        ));
+    }
+}
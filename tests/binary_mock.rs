@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::TransportError,
+        proxy::{codec::encode_request, mock::write_fixture, MockProxy, Proxy, RequestMatcher},
+        BinaryError, Client,
+    };
+    use casper_types::{bytesrepr::Bytes, testing::TestRng, BlockHeader, TestBlockBuilder};
+    use l1_binary_port::{
+        BinaryResponse, BinaryResponseAndRequest, Command, ErrorCode, GetRequest,
+        InformationRequest, InformationRequestTag, NetworkName, Uptime,
+    };
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    fn information_request(req: InformationRequest) -> GetRequest {
+        req.try_into().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mock_proxy_first_matching_rule_applies() {
+        let proxy = MockProxy::new()
+            .with_value(
+                information_request(InformationRequest::Uptime),
+                Uptime::new(1),
+            )
+            .with_value(InformationRequestTag::Uptime, Uptime::new(2))
+            .with_error_code(RequestMatcher::Any, ErrorCode::FunctionDisabled);
+        let client = Client::new(Arc::new(proxy));
+
+        let information = client.get().information();
+        assert_eq!(
+            information.get_uptime().await.unwrap(),
+            Duration::from_secs(1)
+        );
+        assert!(matches!(
+            information.get_network_name().await,
+            Err(BinaryError::Node(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_proxy_records_requests() {
+        let proxy = Arc::new(MockProxy::new());
+        let client = Client::new(proxy.clone());
+
+        let information = client.get().information();
+        let _ = information.get_uptime().await;
+        let _ = information.get_network_name().await;
+        assert_eq!(proxy.request_count(), 2);
+        assert_eq!(
+            proxy.requests(),
+            vec![
+                Command::Get(information_request(InformationRequest::Uptime)),
+                Command::Get(information_request(InformationRequest::NetworkName)),
+            ]
+        );
+
+        proxy.clear_requests();
+        assert!(proxy.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mock_proxy_injects_latency_and_errors() {
+        let latency = Duration::from_millis(50);
+        let proxy = MockProxy::new()
+            .with_latency(InformationRequestTag::NetworkName, latency)
+            .with_value(
+                InformationRequestTag::NetworkName,
+                NetworkName::new("casper-test"),
+            )
+            .with_error(RequestMatcher::Any, || {
+                TransportError::ConnectionClosed.into()
+            });
+        let client = Client::new(Arc::new(proxy));
+
+        let information = client.get().information();
+        let started = Instant::now();
+        assert_eq!(information.get_network_name().await.unwrap(), "casper-test");
+        assert!(started.elapsed() >= latency);
+        assert!(matches!(
+            information.get_uptime().await,
+            Err(BinaryError::Transport(TransportError::ConnectionClosed))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_proxy_loads_fixtures() {
+        let rng = &mut TestRng::new();
+        let header = BlockHeader::from(TestBlockBuilder::new().build(rng).take_header());
+        let request = Command::Get(information_request(InformationRequest::BlockHeader(None)));
+        let message = encode_request(&request, 7).unwrap();
+        let fixture = BinaryResponseAndRequest::new(
+            BinaryResponse::from_value(header.clone()),
+            Bytes::from(message.payload().to_vec()),
+        );
+        let dir = std::env::temp_dir().join(format!("binary-mock-fixtures-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        write_fixture(dir.join("latest_block_header.bin"), &fixture).unwrap();
+        std::fs::write(dir.join("README.md"), "not a fixture").unwrap();
+
+        let proxy = MockProxy::new().with_fixtures(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let client = Client::new(Arc::new(proxy.unwrap()));
+
+        let result = client.get().information().get_block_header(None).await;
+        assert_eq!(result.unwrap(), Some(header));
+    }
+
+    #[tokio::test]
+    async fn test_mock_proxy_echoes_request() {
+        let proxy = MockProxy::new();
+        let request = Command::Get(information_request(InformationRequest::Uptime));
+        let expected = encode_request(&request, 0).unwrap();
+
+        let response = proxy.dispatch(request).await.unwrap();
+        assert_eq!(response.request(), expected.payload());
+    }
+}
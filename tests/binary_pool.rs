@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::{ConfigError, TransportError},
+        proxy::{MockProxy, PoolConfig, PooledProxy, Proxy, RequestMatcher, SelectionStrategy},
+        BinaryError, Client,
+    };
+    use casper_types::{testing::TestRng, Transaction};
+    use l1_binary_port::{InformationRequestTag, Uptime};
+    use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+    fn address(index: usize) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], 28101 + index as u16))
+    }
+
+    fn healthy_node() -> Arc<MockProxy> {
+        Arc::new(MockProxy::new().with_value(InformationRequestTag::Uptime, Uptime::new(1)))
+    }
+
+    fn failing_node() -> Arc<MockProxy> {
+        Arc::new(MockProxy::new().with_error(RequestMatcher::Any, || {
+            TransportError::ConnectionClosed.into()
+        }))
+    }
+
+    fn get_pool(nodes: &[Arc<MockProxy>], config: PoolConfig) -> PooledProxy {
+        let proxies = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (address(index), node.clone() as Arc<dyn Proxy>))
+            .collect();
+        PooledProxy::from_proxies(
+            proxies,
+            PoolConfig {
+                health_check_interval_secs: 0,
+                ..config
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_pool_round_robin() {
+        let nodes = vec![healthy_node(), healthy_node(), healthy_node()];
+        let client = Client::new(Arc::new(get_pool(&nodes, PoolConfig::default())));
+
+        for _ in 0..6 {
+            client.get().information().get_uptime().await.unwrap();
+        }
+        for node in nodes {
+            assert_eq!(node.request_count(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pool_retries_get_on_another_node() {
+        let nodes = [failing_node(), healthy_node()];
+        let pool = Arc::new(get_pool(&nodes, PoolConfig::default()));
+        let client = Client::new(pool.clone());
+
+        let result = client.get().information().get_uptime().await;
+        assert_eq!(result.unwrap(), Duration::from_secs(1));
+        assert_eq!(nodes[0].request_count(), 1);
+        assert_eq!(nodes[1].request_count(), 1);
+        assert_eq!(pool.status()[0].consecutive_failures, 1);
+        assert_eq!(pool.status()[1].consecutive_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_pool_does_not_retry_transaction() {
+        let rng = &mut TestRng::new();
+        let nodes = [failing_node(), healthy_node()];
+        let client = Client::new(Arc::new(get_pool(&nodes, PoolConfig::default())));
+
+        let result = client
+            .try_accept_transaction(Transaction::random(rng))
+            .await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Transport(TransportError::ConnectionClosed))
+        ));
+        assert_eq!(nodes[1].request_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_pool_gives_up_after_max_retries() {
+        let nodes = vec![failing_node(), failing_node(), failing_node()];
+        let config = PoolConfig {
+            max_retries: 1,
+            ..PoolConfig::default()
+        };
+        let client = Client::new(Arc::new(get_pool(&nodes, config)));
+
+        let result = client.get().information().get_uptime().await;
+        assert!(matches!(result, Err(BinaryError::Transport(_))));
+        let attempts: usize = nodes.iter().map(|node| node.request_count()).sum();
+        assert_eq!(attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_pool_ejects_failing_node() {
+        let nodes = [failing_node(), healthy_node()];
+        let config = PoolConfig {
+            ejection_threshold: 2,
+            ..PoolConfig::default()
+        };
+        let pool = Arc::new(get_pool(&nodes, config));
+        let client = Client::new(pool.clone());
+
+        for _ in 0..10 {
+            client.get().information().get_uptime().await.unwrap();
+        }
+        assert_eq!(nodes[0].request_count(), 2);
+        assert!(pool.status()[0].ejected);
+        assert!(!pool.status()[1].ejected);
+    }
+
+    #[tokio::test]
+    async fn test_pool_least_in_flight() {
+        let slow_node = Arc::new(
+            MockProxy::new()
+                .with_latency(RequestMatcher::Any, Duration::from_millis(300))
+                .with_value(InformationRequestTag::Uptime, Uptime::new(1)),
+        );
+        let nodes = vec![slow_node, healthy_node()];
+        let config = PoolConfig {
+            selection: SelectionStrategy::LeastInFlight,
+            ..PoolConfig::default()
+        };
+        let client = Arc::new(Client::new(Arc::new(get_pool(&nodes, config))));
+
+        let slow_client = client.clone();
+        let slow_request =
+            tokio::spawn(async move { slow_client.get().information().get_uptime().await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        for _ in 0..3 {
+            client.get().information().get_uptime().await.unwrap();
+        }
+        slow_request.await.unwrap().unwrap();
+        assert_eq!(nodes[0].request_count(), 1);
+        assert_eq!(nodes[1].request_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_pool_health_checks() {
+        let nodes = [failing_node(), healthy_node()];
+        let proxies = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (address(index), node.clone() as Arc<dyn Proxy>))
+            .collect();
+        let config = PoolConfig {
+            ejection_threshold: 1,
+            health_check_interval_secs: 1,
+            ..PoolConfig::default()
+        };
+        let pool = PooledProxy::from_proxies(proxies, config).unwrap();
+
+        tokio::time::sleep(Duration::from_millis(1200)).await;
+        let status = pool.status();
+        assert!(status[0].ejected);
+        assert!(!status[1].ejected);
+        assert_eq!(nodes[1].request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pool_requires_nodes() {
+        let result = PooledProxy::from_proxies(vec![], PoolConfig::default());
+        assert!(matches!(
+            result,
+            Err(ConfigError::InvalidValue {
+                field: "addresses",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,284 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::ProtocolError, proxy::MockProxy, types::ApprovalsHashes, BinaryError, Client,
+    };
+    use casper_types::{
+        account::AccountHash, bytesrepr::ToBytes, crypto, execution::ExecutionResult,
+        global_state::TrieMerkleProof, testing::TestRng, AccessRights, ApprovalsHash, Block,
+        BlockHeader, BlockIdentifier, BlockSignatures, BlockSignaturesV1, CLValue, Deploy,
+        DeployHash, Digest, Key, PublicKey, SecretKey, StoredValue, TestBlockBuilder,
+        TestBlockV1Builder, Transaction, Transfer, TransferV1, URef, U512,
+    };
+    use l1_binary_port::{
+        BinaryResponse, BinaryResponseAndRequest, GetRequest, InformationRequest, RecordId,
+        ResponseType,
+    };
+    use std::{collections::VecDeque, sync::Arc};
+
+    fn record_request(record_id: RecordId, key: impl ToBytes) -> GetRequest {
+        GetRequest::Record {
+            record_type_tag: record_id.into(),
+            key: key.to_bytes().unwrap(),
+        }
+    }
+
+    fn block_header_request(height: u64) -> GetRequest {
+        InformationRequest::BlockHeader(Some(BlockIdentifier::Height(height)))
+            .try_into()
+            .unwrap()
+    }
+
+    fn get_block(rng: &mut TestRng) -> Block {
+        Block::from(TestBlockBuilder::new().build(rng))
+    }
+
+    #[tokio::test]
+    async fn test_get_block_by_hash_and_height() {
+        let rng = &mut TestRng::new();
+        let block = get_block(rng);
+        let header = block.clone_header();
+        let body = block.clone().take_body();
+        let proxy = MockProxy::new()
+            .with_value(block_header_request(header.height()), header.clone())
+            .with_value(record_request(RecordId::BlockHeader, *block.hash()), header)
+            .with_value(
+                record_request(RecordId::BlockBody, *block.body_hash()),
+                body,
+            );
+        let client = Client::new(Arc::new(proxy));
+
+        for block_id in [
+            BlockIdentifier::Hash(*block.hash()),
+            BlockIdentifier::Height(block.height()),
+        ] {
+            let result = client.get().record().get_block(block_id).await.unwrap();
+            assert_eq!(result, Some(block.clone()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_legacy_block_header() {
+        let rng = &mut TestRng::new();
+        let block = TestBlockV1Builder::new().build(rng);
+        let proxy = MockProxy::new().with_response(
+            record_request(RecordId::BlockHeader, *block.hash()),
+            BinaryResponseAndRequest::new_legacy_test_response(
+                RecordId::BlockHeader,
+                block.header(),
+            )
+            .into(),
+        );
+        let client = Client::new(Arc::new(proxy));
+
+        let result = client
+            .get()
+            .record()
+            .get_block_header(BlockIdentifier::Hash(*block.hash()))
+            .await
+            .unwrap();
+        assert_eq!(result, Some(BlockHeader::V1(block.header().clone())));
+    }
+
+    #[tokio::test]
+    async fn test_get_legacy_block_records() {
+        let rng = &mut TestRng::new();
+        let block = TestBlockV1Builder::new().build(rng);
+        let mut signatures = BlockSignaturesV1::new(*block.hash(), block.era_id());
+        let secret_key = SecretKey::random(rng);
+        signatures.insert_signature(
+            PublicKey::from(&secret_key),
+            crypto::sign(block.hash(), &secret_key, &PublicKey::from(&secret_key)),
+        );
+        let transfers: Vec<TransferV1> = (0..3)
+            .map(|id| {
+                TransferV1::new(
+                    DeployHash::new(Digest::random(rng)),
+                    AccountHash::new(Digest::random(rng).value()),
+                    None,
+                    URef::new(Digest::random(rng).value(), AccessRights::READ_ADD_WRITE),
+                    URef::new(Digest::random(rng).value(), AccessRights::READ_ADD_WRITE),
+                    U512::from(1_000 + id),
+                    U512::from(id),
+                    Some(id),
+                )
+            })
+            .collect();
+        let proxy = MockProxy::new()
+            .with_response(
+                record_request(RecordId::BlockHeader, *block.hash()),
+                BinaryResponseAndRequest::new_legacy_test_response(
+                    RecordId::BlockHeader,
+                    block.header(),
+                )
+                .into(),
+            )
+            .with_response(
+                record_request(RecordId::BlockMetadata, *block.hash()),
+                BinaryResponseAndRequest::new_legacy_test_response(
+                    RecordId::BlockMetadata,
+                    &signatures,
+                )
+                .into(),
+            )
+            // Legacy transfers share the response type of current transfers.
+            .with_response(
+                record_request(RecordId::Transfer, *block.hash()),
+                BinaryResponse::from_raw_bytes(
+                    ResponseType::Transfers,
+                    bincode::serialize(&transfers).unwrap(),
+                ),
+            );
+        let client = Client::new(Arc::new(proxy));
+        let block_id = BlockIdentifier::Hash(*block.hash());
+
+        let record = client.get().record();
+        assert_eq!(
+            record.get_finality_signatures(block_id).await.unwrap(),
+            Some(BlockSignatures::V1(signatures))
+        );
+        assert_eq!(
+            record.get_transfers(block_id).await.unwrap(),
+            Some(transfers.into_iter().map(Transfer::from).collect())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_block_not_found() {
+        let rng = &mut TestRng::new();
+        let block = get_block(rng);
+        let client = Client::new(Arc::new(MockProxy::new()));
+
+        for block_id in [
+            BlockIdentifier::Hash(*block.hash()),
+            BlockIdentifier::Height(block.height()),
+        ] {
+            let record = client.get().record();
+            assert!(matches!(record.get_block(block_id).await, Ok(None)));
+            assert!(matches!(record.get_transfers(block_id).await, Ok(None)));
+            assert!(matches!(
+                record.get_finality_signatures(block_id).await,
+                Ok(None)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_block_records_by_height() {
+        let rng = &mut TestRng::new();
+        let block = get_block(rng);
+        let signatures = BlockSignatures::random(rng);
+        let transfers: Vec<Transfer> = (0..3).map(|_| Transfer::random(rng)).collect();
+        let approvals_hashes = ApprovalsHashes {
+            block_hash: *block.hash(),
+            approvals_hashes: vec![ApprovalsHash(Digest::hash([1; 8]))],
+            merkle_proof_approvals: TrieMerkleProof::new(
+                Key::ChecksumRegistry,
+                StoredValue::CLValue(CLValue::from_t(1_u64).unwrap()),
+                VecDeque::new(),
+            ),
+        };
+        let proxy = MockProxy::new()
+            .with_value(block_header_request(block.height()), block.clone_header())
+            .with_value(
+                record_request(RecordId::BlockMetadata, *block.hash()),
+                signatures.clone(),
+            )
+            .with_value(
+                record_request(RecordId::Transfer, *block.hash()),
+                transfers.clone(),
+            )
+            .with_value(
+                record_request(RecordId::ApprovalsHashes, *block.hash()),
+                approvals_hashes.clone(),
+            );
+        let client = Client::new(Arc::new(proxy));
+        let block_id = BlockIdentifier::Height(block.height());
+
+        let record = client.get().record();
+        assert_eq!(
+            record.get_finality_signatures(block_id).await.unwrap(),
+            Some(signatures)
+        );
+        assert_eq!(
+            record.get_transfers(block_id).await.unwrap(),
+            Some(transfers)
+        );
+        assert_eq!(
+            record.get_approvals_hashes(block_id).await.unwrap(),
+            Some(approvals_hashes)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_and_execution_result() {
+        let rng = &mut TestRng::new();
+        let transaction = Transaction::random(rng);
+        let deploy = Deploy::random(rng);
+        let execution_result = ExecutionResult::random(rng);
+        let proxy = MockProxy::new()
+            .with_value(
+                record_request(RecordId::Transaction, transaction.hash()),
+                transaction.clone(),
+            )
+            .with_response(
+                record_request(
+                    RecordId::Transaction,
+                    Transaction::from(deploy.clone()).hash(),
+                ),
+                BinaryResponseAndRequest::new_legacy_test_response(RecordId::Transaction, &deploy)
+                    .into(),
+            )
+            .with_value(
+                record_request(RecordId::ExecutionResult, transaction.hash()),
+                execution_result.clone(),
+            );
+        let client = Client::new(Arc::new(proxy));
+
+        let record = client.get().record();
+        assert_eq!(
+            record.get_transaction(transaction.hash()).await.unwrap(),
+            Some(transaction.clone())
+        );
+        assert_eq!(
+            record
+                .get_transaction(Transaction::from(deploy.clone()).hash())
+                .await
+                .unwrap(),
+            Some(Transaction::from(deploy))
+        );
+        assert_eq!(
+            record
+                .get_execution_result(transaction.hash())
+                .await
+                .unwrap(),
+            Some(execution_result)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_record_unexpected_response_type() {
+        let rng = &mut TestRng::new();
+        let block = get_block(rng);
+        let proxy = MockProxy::new().with_value(
+            record_request(RecordId::BlockHeader, *block.hash()),
+            Transaction::random(rng),
+        );
+        let client = Client::new(Arc::new(proxy));
+
+        let result = client
+            .get()
+            .record()
+            .get_block_header(BlockIdentifier::Hash(*block.hash()))
+            .await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Protocol(
+                ProtocolError::UnexpectedResponseType {
+                    expected: ResponseType::BlockHeader,
+                    ..
+                }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+#![cfg(feature = "testing")]
+
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::{ProtocolError, TransportError},
+        proxy::{
+            ExponentialBackoffConfig, MaxAttempts, PoolConfig, PooledProxy, ProxyConfig,
+            RemoteProxy,
+        },
+        server::{FakeServer, FakeServerConfig, FakeStore},
+        BinaryError, Client,
+    };
+    use casper_types::{
+        testing::TestRng, Block, BlockIdentifier, CLValue, Key, StoredValue, TestBlockBuilder,
+        Transaction,
+    };
+    use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+    const MAX_MESSAGE_SIZE_BYTES: u32 = 4 * 1024 * 1024;
+
+    fn get_config(address: SocketAddr) -> ProxyConfig {
+        ProxyConfig {
+            address,
+            max_message_size_bytes: MAX_MESSAGE_SIZE_BYTES,
+            message_timeout_secs: 1,
+            client_access_timeout_secs: 5,
+            request_limit: 1,
+            request_buffer_size: 1,
+            exponential_backoff: ExponentialBackoffConfig {
+                initial_delay_ms: 10,
+                max_delay_ms: 100,
+                coefficient: 2,
+                max_attempts: MaxAttempts::Finite(3),
+            },
+        }
+    }
+
+    fn get_client(server: &FakeServer) -> Client {
+        Client::new(Arc::new(RemoteProxy::new(get_config(server.address()))))
+    }
+
+    fn get_block(rng: &mut TestRng, height: u64) -> Block {
+        Block::from(TestBlockBuilder::new().height(height).build(rng))
+    }
+
+    #[tokio::test]
+    async fn test_fake_server_serves_store() {
+        let rng = &mut TestRng::new();
+        let blocks: Vec<Block> = (0..3).map(|height| get_block(rng, height)).collect();
+        let transaction = Transaction::random(rng);
+        let value = StoredValue::CLValue(CLValue::from_t(7_u64).unwrap());
+        let store = blocks
+            .iter()
+            .cloned()
+            .fold(FakeStore::new("casper-test"), FakeStore::with_block)
+            .with_transaction(transaction.clone())
+            .with_item(Key::Hash([1; 32]), value.clone());
+        let server = FakeServer::start(store).await.unwrap();
+        let client = get_client(&server);
+
+        let record = client.get().record();
+        for block in &blocks {
+            for block_id in [
+                BlockIdentifier::Hash(*block.hash()),
+                BlockIdentifier::Height(block.height()),
+            ] {
+                assert_eq!(
+                    record.get_block(block_id).await.unwrap().as_ref(),
+                    Some(block)
+                );
+            }
+        }
+        assert_eq!(
+            client
+                .get()
+                .information()
+                .get_block_header(None)
+                .await
+                .unwrap(),
+            Some(blocks[2].clone_header())
+        );
+        assert_eq!(
+            record.get_transaction(transaction.hash()).await.unwrap(),
+            Some(transaction)
+        );
+        assert_eq!(
+            client.get().information().get_network_name().await.unwrap(),
+            "casper-test"
+        );
+        let result = client
+            .get()
+            .state()
+            .get_item(None, Key::Hash([1; 32]), vec![])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.value(), &value);
+        assert_eq!(server.connection_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fake_server_accepts_transaction() {
+        let rng = &mut TestRng::new();
+        let server = FakeServer::start(FakeStore::new("casper-test"))
+            .await
+            .unwrap();
+        let client = get_client(&server);
+        let transaction = Transaction::random(rng);
+
+        client
+            .try_accept_transaction(transaction.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            server.store().transaction(&transaction.hash()),
+            Some(&transaction)
+        );
+        assert!(client.try_speculative_exec(transaction).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fake_server_message_timeout_and_reconnect() {
+        let server = FakeServer::start(FakeStore::new("casper-test"))
+            .await
+            .unwrap();
+        let client = get_client(&server);
+        let information = client.get().information();
+
+        server.set_response_delay(Duration::from_millis(1500));
+        assert!(matches!(
+            information.get_network_name().await,
+            Err(BinaryError::Transport(TransportError::MessageTimeout))
+        ));
+
+        server.set_response_delay(Duration::ZERO);
+        assert_eq!(information.get_network_name().await.unwrap(), "casper-test");
+        assert_eq!(server.connection_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fake_server_silent() {
+        let server = FakeServer::start(FakeStore::new("casper-test"))
+            .await
+            .unwrap();
+        let client = get_client(&server);
+
+        server.set_silent(true);
+        assert!(matches!(
+            client.get().information().get_network_name().await,
+            Err(BinaryError::Transport(TransportError::MessageTimeout))
+        ));
+        assert_eq!(server.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fake_server_disconnect() {
+        let server = FakeServer::start(FakeStore::new("casper-test"))
+            .await
+            .unwrap();
+        let client = get_client(&server);
+        let information = client.get().information();
+
+        assert_eq!(information.get_network_name().await.unwrap(), "casper-test");
+        server.disconnect_all();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(information.get_network_name().await.unwrap(), "casper-test");
+        assert_eq!(server.connection_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fake_server_message_size_limits() {
+        let rng = &mut TestRng::new();
+        let config = FakeServerConfig {
+            max_message_size_bytes: 64,
+            ..FakeServerConfig::default()
+        };
+        let server = FakeServer::start_with_config(FakeStore::new("casper-test"), config)
+            .await
+            .unwrap();
+
+        // Oversized request rejected by the server, which drops the connection.
+        let client = get_client(&server);
+        let transaction = Transaction::random(rng);
+        assert!(matches!(
+            client.try_accept_transaction(transaction.clone()).await,
+            Err(BinaryError::Transport(TransportError::ConnectionClosed))
+        ));
+        assert!(server.store().transaction(&transaction.hash()).is_none());
+
+        // Oversized request rejected by the client, without reaching the server.
+        let mut proxy_config = get_config(server.address());
+        proxy_config.max_message_size_bytes = 64;
+        let client = Client::new(Arc::new(RemoteProxy::new(proxy_config)));
+        let requests = server.request_count();
+        assert!(matches!(
+            client
+                .try_accept_transaction(Transaction::random(rng))
+                .await,
+            Err(BinaryError::Protocol(ProtocolError::MessageError(
+                l1_binary_port::Error::RequestTooLarge { allowed: 64, .. }
+            )))
+        ));
+        assert_eq!(server.request_count(), requests);
+    }
+
+    #[tokio::test]
+    async fn test_pooled_proxy_survives_node_loss() {
+        let store = FakeStore::new("casper-test");
+        let mut servers = vec![
+            FakeServer::start(store.clone()).await.unwrap(),
+            FakeServer::start(store).await.unwrap(),
+        ];
+        let config = PoolConfig {
+            addresses: servers.iter().map(FakeServer::address).collect(),
+            health_check_interval_secs: 0,
+            ..PoolConfig::default()
+        };
+        let proxy_config = ProxyConfig {
+            exponential_backoff: ExponentialBackoffConfig {
+                max_attempts: MaxAttempts::Finite(1),
+                ..ExponentialBackoffConfig::default()
+            },
+            ..get_config(servers[0].address())
+        };
+        let client = Client::new(Arc::new(PooledProxy::new(proxy_config, config).unwrap()));
+        let information = client.get().information();
+
+        assert_eq!(information.get_network_name().await.unwrap(), "casper-test");
+        drop(servers.remove(0));
+        for _ in 0..4 {
+            assert_eq!(information.get_network_name().await.unwrap(), "casper-test");
+        }
+    }
+}
@@ -0,0 +1,429 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::ProtocolError,
+        proxy::MockProxy,
+        types::{
+            BalanceResponse, DictionaryItemIdentifier, DictionaryQueryResult,
+            GlobalStateQueryResult, PurseIdentifier,
+        },
+        BinaryError, Client,
+    };
+    use casper_sdk_rs::crypto::{merkle::compute_state_root_hash, ProofError};
+    use casper_types::{
+        account::{Account, AccountHash},
+        addressable_entity::{NamedKeyAddr, NamedKeyValue},
+        bytesrepr::{Bytes, ToBytes},
+        global_state::{Pointer, TrieMerkleProof, TrieMerkleProofStep},
+        AccessRights, AddressableEntity, CLValue, CLValueDictionary, Digest, EntityAddr,
+        GlobalStateIdentifier, Key, KeyTag, NamedKeys, StoredValue, URef, U512,
+    };
+    use l1_binary_port::{
+        GetRequest, GetTrieFullResult, GlobalStateEntityQualifier, GlobalStateRequest,
+    };
+    use std::{
+        collections::{BTreeMap, VecDeque},
+        sync::Arc,
+    };
+
+    const STATE_ID: Option<GlobalStateIdentifier> = Some(GlobalStateIdentifier::BlockHeight(7));
+
+    fn state_request(qualifier: GlobalStateEntityQualifier) -> GetRequest {
+        GetRequest::State(Box::new(GlobalStateRequest::new(STATE_ID, qualifier)))
+    }
+
+    fn item_request(base_key: Key) -> GetRequest {
+        state_request(GlobalStateEntityQualifier::Item {
+            base_key,
+            path: vec![],
+        })
+    }
+
+    fn proof(key: Key, value: StoredValue) -> TrieMerkleProof<Key, StoredValue> {
+        TrieMerkleProof::new(key, value, VecDeque::new())
+    }
+
+    fn query_result(key: Key, value: StoredValue) -> GlobalStateQueryResult {
+        GlobalStateQueryResult::new(value.clone(), vec![proof(key, value)])
+    }
+
+    fn cl_value<T: casper_types::CLTyped + ToBytes>(value: T) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(value).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_get_item() {
+        let key = Key::Hash([1; 32]);
+        let qualifier = GlobalStateEntityQualifier::Item {
+            base_key: key,
+            path: vec!["counter".to_string()],
+        };
+        let proxy = MockProxy::new()
+            .with_value(state_request(qualifier), query_result(key, cl_value(9_u32)));
+        let client = Client::new(Arc::new(proxy));
+
+        let state = client.get().state();
+        let result = state
+            .get_item(STATE_ID, key, vec!["counter".to_string()])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.value(), &cl_value(9_u32));
+        assert!(matches!(
+            state.get_item(STATE_ID, key, vec![]).await,
+            Ok(None)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_all_items() {
+        let values = vec![cl_value(1_u8), cl_value(2_u8)];
+        let proxy = MockProxy::new().with_value(
+            state_request(GlobalStateEntityQualifier::AllItems {
+                key_tag: KeyTag::Hash,
+            }),
+            values.clone(),
+        );
+        let client = Client::new(Arc::new(proxy));
+
+        let state = client.get().state();
+        assert_eq!(
+            state.get_all_items(STATE_ID, KeyTag::Hash).await.unwrap(),
+            values
+        );
+        assert!(state
+            .get_all_items(STATE_ID, KeyTag::URef)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_balance() {
+        let purse = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        let balance = BalanceResponse {
+            total_balance: U512::from(100),
+            available_balance: U512::from(60),
+            total_balance_proof: Box::new(proof(
+                Key::Balance(purse.addr()),
+                cl_value(U512::from(100)),
+            )),
+            balance_holds: BTreeMap::new(),
+        };
+        let proxy = MockProxy::new().with_value(
+            state_request(GlobalStateEntityQualifier::Balance {
+                purse_identifier: PurseIdentifier::Purse(purse),
+            }),
+            balance.clone(),
+        );
+        let client = Client::new(Arc::new(proxy));
+
+        let state = client.get().state();
+        assert_eq!(
+            state
+                .get_balance(STATE_ID, PurseIdentifier::Purse(purse))
+                .await
+                .unwrap(),
+            Some(balance)
+        );
+        assert!(matches!(
+            state
+                .get_balance(
+                    STATE_ID,
+                    PurseIdentifier::Account(AccountHash::new([3; 32]))
+                )
+                .await,
+            Ok(None)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_dictionary_item() {
+        let identifier = DictionaryItemIdentifier::URef {
+            seed_uref: URef::new([4; 32], AccessRights::READ),
+            dictionary_item_key: "item".to_string(),
+        };
+        let key = Key::Dictionary([5; 32]);
+        let proxy = MockProxy::new().with_value(
+            state_request(GlobalStateEntityQualifier::DictionaryItem {
+                identifier: identifier.clone(),
+            }),
+            DictionaryQueryResult::new(key, query_result(key, cl_value("value".to_string()))),
+        );
+        let client = Client::new(Arc::new(proxy));
+
+        let (result_key, result) = client
+            .get()
+            .state()
+            .get_dictionary_item(STATE_ID, identifier)
+            .await
+            .unwrap()
+            .unwrap()
+            .into_inner();
+        assert_eq!(result_key, key);
+        assert_eq!(result.value(), &cl_value("value".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_trie() {
+        let trie_key = Digest::hash([6; 8]);
+        let proxy = MockProxy::new().with_value(
+            GetRequest::Trie { trie_key },
+            GetTrieFullResult::new(Some(Bytes::from(vec![1, 2, 3]))),
+        );
+        let client = Client::new(Arc::new(proxy));
+
+        let state = client.get().state();
+        assert_eq!(
+            state.get_trie(trie_key).await.unwrap(),
+            Some(Bytes::from(vec![1, 2, 3]))
+        );
+        assert!(matches!(
+            state.get_trie(Digest::hash([7; 8])).await,
+            Ok(None)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_verified_item() {
+        let key = Key::Hash([1; 32]);
+        let value = cl_value(9_u32);
+        let state_root_hash = compute_state_root_hash(&proof(key, value.clone())).unwrap();
+        let forged_root_hash = Digest::hash([8; 8]);
+        let request = |state_root_hash| {
+            GetRequest::State(Box::new(GlobalStateRequest::new(
+                Some(GlobalStateIdentifier::StateRootHash(state_root_hash)),
+                GlobalStateEntityQualifier::Item {
+                    base_key: key,
+                    path: vec![],
+                },
+            )))
+        };
+        let proxy = MockProxy::new()
+            .with_value(request(state_root_hash), query_result(key, value.clone()))
+            .with_value(request(forged_root_hash), query_result(key, value.clone()));
+        let client = Client::new(Arc::new(proxy));
+
+        let state = client.get().state();
+        assert_eq!(
+            state
+                .get_verified_item(state_root_hash, key, vec![])
+                .await
+                .unwrap(),
+            Some(value)
+        );
+        match state.get_verified_item(forged_root_hash, key, vec![]).await {
+            Err(BinaryError::Protocol(ProtocolError::InvalidProof(err))) => {
+                assert!(matches!(*err, ProofError::StateRootHashMismatch { .. }))
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_verified_dictionary_item() {
+        let account_hash = AccountHash::new([16; 32]);
+        let seed_uref = URef::new([17; 32], AccessRights::READ_ADD_WRITE);
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert("balances".to_string(), Key::URef(seed_uref));
+        let account = Account::create(
+            account_hash,
+            named_keys,
+            URef::new([18; 32], AccessRights::READ_ADD_WRITE),
+        );
+        let item = |name: &str, value: u64| {
+            let key = Key::dictionary(seed_uref, name.as_bytes());
+            let dictionary_value = CLValueDictionary::new(
+                CLValue::from_t(value).unwrap(),
+                seed_uref.addr().to_vec(),
+                name.as_bytes().to_vec(),
+            );
+            (key, cl_value(dictionary_value))
+        };
+        let entries = [
+            (Key::Account(account_hash), StoredValue::Account(account)),
+            item("alice", 5),
+            item("bob", 6),
+        ];
+        // Entries held as leaves of a single node.
+        let leaf_hashes: Vec<(u8, Pointer)> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, (key, value))| {
+                let leaf = compute_state_root_hash(&proof(*key, value.clone())).unwrap();
+                (index as u8, Pointer::LeafPointer(leaf))
+            })
+            .collect();
+        let proofs: Vec<_> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, (key, value))| {
+                let siblings = leaf_hashes
+                    .iter()
+                    .filter(|(sibling, _)| *sibling as usize != index)
+                    .cloned()
+                    .collect();
+                let step = TrieMerkleProofStep::node(index as u8, siblings);
+                TrieMerkleProof::new(*key, value.clone(), VecDeque::from([step]))
+            })
+            .collect();
+        let state_root_hash = compute_state_root_hash(&proofs[0]).unwrap();
+        let state_id = Some(GlobalStateIdentifier::StateRootHash(state_root_hash));
+        let request =
+            |qualifier| GetRequest::State(Box::new(GlobalStateRequest::new(state_id, qualifier)));
+        let identifier = |dictionary_item_key: &str| DictionaryItemIdentifier::AccountNamedKey {
+            hash: account_hash,
+            dictionary_name: "balances".to_string(),
+            dictionary_item_key: dictionary_item_key.to_string(),
+        };
+        let item_result = |index: usize, value: u64| {
+            DictionaryQueryResult::new(
+                entries[index].0,
+                GlobalStateQueryResult::new(cl_value(value), vec![proofs[index].clone()]),
+            )
+        };
+        let proxy = MockProxy::new()
+            .with_value(
+                request(GlobalStateEntityQualifier::Item {
+                    base_key: Key::Account(account_hash),
+                    path: vec![],
+                }),
+                GlobalStateQueryResult::new(entries[0].1.clone(), vec![proofs[0].clone()]),
+            )
+            .with_value(
+                request(GlobalStateEntityQualifier::DictionaryItem {
+                    identifier: identifier("alice"),
+                }),
+                item_result(1, 5),
+            )
+            // Node answers with another, validly proven, item of the dictionary.
+            .with_value(
+                request(GlobalStateEntityQualifier::DictionaryItem {
+                    identifier: identifier("carol"),
+                }),
+                item_result(2, 6),
+            );
+        let client = Client::new(Arc::new(proxy));
+
+        let state = client.get().state();
+        assert_eq!(
+            state
+                .get_verified_dictionary_item(state_root_hash, identifier("alice"))
+                .await
+                .unwrap(),
+            Some((entries[1].0, cl_value(5_u64)))
+        );
+        match state
+            .get_verified_dictionary_item(state_root_hash, identifier("carol"))
+            .await
+        {
+            Err(BinaryError::Protocol(ProtocolError::InvalidProof(err))) => {
+                assert!(matches!(*err, ProofError::KeyMismatch { .. }))
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_named_key_of_account() {
+        let account_hash = AccountHash::new([8; 32]);
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert("contract".to_string(), Key::Hash([9; 32]));
+        let account = Account::create(
+            account_hash,
+            named_keys,
+            URef::new([10; 32], AccessRights::READ_ADD_WRITE),
+        );
+        let proxy = MockProxy::new().with_value(
+            item_request(Key::Account(account_hash)),
+            query_result(Key::Account(account_hash), StoredValue::Account(account)),
+        );
+        let client = Client::new(Arc::new(proxy));
+
+        let state = client.get().state();
+        assert_eq!(
+            state
+                .get_named_key(STATE_ID, Key::Account(account_hash), "contract")
+                .await
+                .unwrap(),
+            Some(Key::Hash([9; 32]))
+        );
+        assert_eq!(
+            state
+                .get_named_key(STATE_ID, Key::Account(account_hash), "missing")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_named_key_of_migrated_account() {
+        let account_hash = AccountHash::new([11; 32]);
+        let entity_addr = EntityAddr::new_account([12; 32]);
+        let named_key_addr =
+            NamedKeyAddr::new_from_string(entity_addr, "contract".to_string()).unwrap();
+        let named_key =
+            NamedKeyValue::from_concrete_values(Key::Hash([13; 32]), "contract".to_string())
+                .unwrap();
+        let proxy = MockProxy::new()
+            .with_value(
+                item_request(Key::Account(account_hash)),
+                query_result(
+                    Key::Account(account_hash),
+                    cl_value(Key::AddressableEntity(entity_addr)),
+                ),
+            )
+            .with_value(
+                item_request(Key::AddressableEntity(entity_addr)),
+                query_result(
+                    Key::AddressableEntity(entity_addr),
+                    StoredValue::AddressableEntity(AddressableEntity::default()),
+                ),
+            )
+            .with_value(
+                item_request(Key::NamedKey(named_key_addr)),
+                query_result(
+                    Key::NamedKey(named_key_addr),
+                    StoredValue::NamedKey(named_key),
+                ),
+            );
+        let client = Client::new(Arc::new(proxy));
+
+        let result = client
+            .get()
+            .state()
+            .get_named_key(STATE_ID, Key::Account(account_hash), "contract")
+            .await
+            .unwrap();
+        assert_eq!(result, Some(Key::Hash([13; 32])));
+    }
+
+    #[tokio::test]
+    async fn test_get_named_key_bounds_redirects() {
+        let first = AccountHash::new([14; 32]);
+        let second = AccountHash::new([15; 32]);
+        // Accounts redirecting to one another would otherwise be followed indefinitely.
+        let proxy = MockProxy::new()
+            .with_value(
+                item_request(Key::Account(first)),
+                query_result(Key::Account(first), cl_value(Key::Account(second))),
+            )
+            .with_value(
+                item_request(Key::Account(second)),
+                query_result(Key::Account(second), cl_value(Key::Account(first))),
+            );
+        let client = Client::new(Arc::new(proxy));
+
+        let result = client
+            .get()
+            .state()
+            .get_named_key(STATE_ID, Key::Account(first), "contract")
+            .await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Protocol(ProtocolError::TooManyRedirects(key)))
+                if key == Key::Account(second)
+        ));
+    }
+}
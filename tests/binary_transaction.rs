@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::NodeError,
+        proxy::MockProxy,
+        types::{ErrorCode, SpeculativeExecutionResult},
+        BinaryError, Client,
+    };
+    use casper_types::{testing::TestRng, Deploy, Transaction};
+    use l1_binary_port::{Command, CommandTag};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_try_accept_transaction() {
+        let rng = &mut TestRng::new();
+        let proxy = Arc::new(MockProxy::new());
+        let client = Client::new(proxy.clone());
+        let transaction = Transaction::random(rng);
+        let deploy = Transaction::from(Deploy::random(rng));
+
+        let result = client.try_accept_transaction(transaction.clone()).await;
+        assert!(result.is_ok());
+        let result = client.try_accept_transaction(deploy.clone()).await;
+        assert!(result.is_ok());
+        assert_eq!(
+            proxy.requests(),
+            vec![
+                Command::TryAcceptTransaction { transaction },
+                Command::TryAcceptTransaction {
+                    transaction: deploy
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_accept_transaction_rejected() {
+        let rng = &mut TestRng::new();
+        let client = Client::new(Arc::new(MockProxy::new().with_error_code(
+            CommandTag::TryAcceptTransaction,
+            ErrorCode::InvalidTransactionChainName,
+        )));
+
+        let result = client
+            .try_accept_transaction(Transaction::random(rng))
+            .await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Node(NodeError::InvalidTransaction(
+                ErrorCode::InvalidTransactionChainName
+            )))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_try_speculative_exec() {
+        let rng = &mut TestRng::new();
+        let expected = SpeculativeExecutionResult::example().clone();
+        let client = Client::new(Arc::new(
+            MockProxy::new().with_value(CommandTag::TrySpeculativeExec, expected.clone()),
+        ));
+
+        let result = client
+            .try_speculative_exec(Transaction::from(Deploy::random(rng)))
+            .await
+            .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[tokio::test]
+    async fn test_try_speculative_exec_rejected() {
+        let rng = &mut TestRng::new();
+        let client = Client::new(Arc::new(MockProxy::new().with_error_code(
+            CommandTag::TrySpeculativeExec,
+            ErrorCode::ReceivedV1Transaction,
+        )));
+
+        let result = client.try_speculative_exec(Transaction::random(rng)).await;
+        assert!(matches!(
+            result,
+            Err(BinaryError::Node(NodeError::UnsupportedRequest(
+                ErrorCode::ReceivedV1Transaction
+            )))
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::node::binary::{
+        error::ProtocolError, proxy::MockProxy, types::Trie, BinaryError, Client,
+    };
+    use casper_types::{
+        account::AccountHash, bytesrepr::ToBytes, global_state::Pointer, AccessRights, CLValue,
+        Digest, Key, KeyTag, StoredValue, URef,
+    };
+    use futures::StreamExt;
+    use l1_binary_port::{GetRequest, GetTrieFullResult};
+    use std::sync::Arc;
+
+    fn cl_value(value: u64) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(value).unwrap())
+    }
+
+    /// Global state trie served by a mock node.
+    #[derive(Default)]
+    struct TestTrie {
+        proxy: MockProxy,
+    }
+
+    impl TestTrie {
+        fn put(&mut self, trie: &Trie) -> Digest {
+            let bytes = trie.to_bytes().unwrap();
+            let trie_key = Digest::hash(&bytes);
+            self.proxy = std::mem::take(&mut self.proxy).with_value(
+                GetRequest::Trie { trie_key },
+                GetTrieFullResult::new(Some(bytes.into())),
+            );
+            trie_key
+        }
+
+        fn leaf(&mut self, key: Key, value: StoredValue) -> Pointer {
+            let value = Box::new(value);
+            Pointer::LeafPointer(self.put(&Trie::Leaf { key, value }))
+        }
+
+        fn node(&mut self, pointers: Vec<(u8, Pointer)>) -> Pointer {
+            Pointer::NodePointer(self.put(&Trie::Node { pointers }))
+        }
+    }
+
+    fn entries() -> Vec<(Key, StoredValue)> {
+        let uref = |access_rights| Key::URef(URef::new([5; 32], access_rights));
+        vec![
+            (Key::Account(AccountHash::new([9; 32])), cl_value(0)),
+            (Key::Hash([1; 32]), cl_value(1)),
+            (Key::Hash([2; 32]), cl_value(2)),
+            (uref(AccessRights::READ), cl_value(3)),
+            (uref(AccessRights::WRITE), cl_value(4)),
+        ]
+    }
+
+    /// Builds a trie of the entries, branching by key tag at the root, then by the first byte of
+    /// hashes, with an extension over the address shared by the urefs.
+    fn get_trie() -> (TestTrie, Digest) {
+        let entries = entries();
+        let mut trie = TestTrie::default();
+        let account = trie.leaf(entries[0].0, entries[0].1.clone());
+        let hash_1 = trie.leaf(entries[1].0, entries[1].1.clone());
+        let hash_2 = trie.leaf(entries[2].0, entries[2].1.clone());
+        let hashes = trie.node(vec![(1, hash_1), (2, hash_2)]);
+        let uref_read = trie.leaf(entries[3].0, entries[3].1.clone());
+        let uref_write = trie.leaf(entries[4].0, entries[4].1.clone());
+        let urefs = trie.node(vec![
+            (AccessRights::READ.bits(), uref_read),
+            (AccessRights::WRITE.bits(), uref_write),
+        ]);
+        let urefs = Pointer::NodePointer(trie.put(&Trie::Extension {
+            affix: vec![5; 32].into(),
+            pointer: urefs,
+        }));
+        let root = trie.node(vec![
+            (KeyTag::Account as u8, account),
+            (KeyTag::Hash as u8, hashes),
+            (KeyTag::URef as u8, urefs),
+        ]);
+        (trie, *root.hash())
+    }
+
+    #[tokio::test]
+    async fn test_walk_trie() {
+        let (trie, state_root_hash) = get_trie();
+        let client = Client::new(Arc::new(trie.proxy));
+
+        let walked: Vec<_> = client
+            .get()
+            .state()
+            .walk_trie(state_root_hash, vec![])
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(walked, entries());
+    }
+
+    #[tokio::test]
+    async fn test_walk_trie_by_prefix() {
+        let (trie, state_root_hash) = get_trie();
+        let proxy = Arc::new(trie.proxy);
+        let client = Client::new(proxy.clone());
+        let state = client.get().state();
+
+        let walked: Vec<_> = state
+            .walk_trie_by_tag(state_root_hash, KeyTag::Hash)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(walked, entries()[1..3]);
+        // Root, node of hashes & their leaves.
+        assert_eq!(proxy.request_count(), 4);
+
+        let walked: Vec<_> = state
+            .walk_trie(state_root_hash, vec![KeyTag::URef as u8, 5, 5])
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(walked, entries()[3..]);
+
+        let walked: Vec<_> = state
+            .walk_trie(state_root_hash, vec![KeyTag::URef as u8, 6])
+            .collect()
+            .await;
+        assert!(walked.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_walk_trie_rejects_forged_trie() {
+        let (_, state_root_hash) = get_trie();
+        // Node substitutes the trie of another root.
+        let forged = Trie::Leaf {
+            key: Key::Hash([1; 32]),
+            value: Box::new(cl_value(7)),
+        };
+        let proxy = MockProxy::new()
+            .with_value(
+                GetRequest::Trie {
+                    trie_key: state_root_hash,
+                },
+                GetTrieFullResult::new(Some(forged.to_bytes().unwrap().into())),
+            )
+            .with_value(
+                GetRequest::Trie {
+                    trie_key: Digest::hash([0; 8]),
+                },
+                GetTrieFullResult::new(None),
+            );
+        let client = Client::new(Arc::new(proxy));
+        let state = client.get().state();
+
+        let walked: Vec<_> = state.walk_trie(state_root_hash, vec![]).collect().await;
+        assert_eq!(walked.len(), 1);
+        assert!(matches!(
+            walked[0],
+            Err(BinaryError::Protocol(
+                ProtocolError::TrieHashMismatch { .. }
+            ))
+        ));
+
+        let walked: Vec<_> = state
+            .walk_trie(Digest::hash([0; 8]), vec![])
+            .collect()
+            .await;
+        assert!(matches!(
+            walked[..],
+            [Err(BinaryError::Protocol(ProtocolError::MissingTrie(_)))]
+        ));
+    }
+}
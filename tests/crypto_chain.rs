@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::crypto::{
+        chain::{verify_block, verify_block_hash, verify_header_chain},
+        ChainError, HeaderChainVerifier,
+    };
+    use casper_types::{
+        testing::TestRng, Block, BlockHash, BlockHeader, BlockV2, Digest, EraEndV2, EraId,
+        ProtocolVersion, PublicKey, RewardedSignatures, TestBlockBuilder, Timestamp, U512,
+    };
+    use std::collections::BTreeMap;
+
+    struct ChainBuilder {
+        validators: Vec<PublicKey>,
+        blocks: Vec<BlockV2>,
+        last_switch_block_hash: Option<BlockHash>,
+    }
+
+    impl ChainBuilder {
+        /// Starts a chain at a switch block, with validators for the following era.
+        fn new(rng: &mut TestRng) -> Self {
+            let validators: Vec<PublicKey> = (0..3).map(|_| PublicKey::random(rng)).collect();
+            let mut builder = ChainBuilder {
+                validators,
+                blocks: vec![],
+                last_switch_block_hash: None,
+            };
+            let proposer = builder.validators[0].clone();
+            builder.push(rng, BlockHash::default(), EraId::new(0), 0, true, proposer);
+            builder
+        }
+
+        fn weights(&self) -> BTreeMap<PublicKey, U512> {
+            self.validators
+                .iter()
+                .map(|validator| (validator.clone(), U512::from(100)))
+                .collect()
+        }
+
+        /// Appends a block proposed by a validator, ending the era if `is_switch`.
+        fn extend(&mut self, rng: &mut TestRng, is_switch: bool) -> &mut Self {
+            let parent = self.blocks.last().unwrap().header().clone();
+            let proposer = self.validators[self.blocks.len() % self.validators.len()].clone();
+            self.push(
+                rng,
+                parent.block_hash(),
+                BlockHeader::V2(parent.clone()).next_block_era_id(),
+                parent.height() + 1,
+                is_switch,
+                proposer,
+            );
+            self
+        }
+
+        fn push(
+            &mut self,
+            rng: &mut TestRng,
+            parent_hash: BlockHash,
+            era_id: EraId,
+            height: u64,
+            is_switch: bool,
+            proposer: PublicKey,
+        ) {
+            let era_end = is_switch
+                .then(|| EraEndV2::new(vec![], vec![], self.weights(), BTreeMap::new(), 1));
+            let block = BlockV2::new(
+                parent_hash,
+                Digest::random(rng),
+                Digest::random(rng),
+                false,
+                era_end,
+                Timestamp::now(),
+                era_id,
+                height,
+                ProtocolVersion::V2_0_0,
+                proposer,
+                BTreeMap::new(),
+                RewardedSignatures::default(),
+                1,
+                self.last_switch_block_hash,
+            );
+            if is_switch {
+                self.last_switch_block_hash = Some(*block.hash());
+            }
+            self.blocks.push(block);
+        }
+
+        fn headers(&self) -> Vec<BlockHeader> {
+            self.blocks
+                .iter()
+                .map(|block| BlockHeader::V2(block.header().clone()))
+                .collect()
+        }
+    }
+
+    /// Chain spanning three eras.
+    fn get_chain(rng: &mut TestRng) -> ChainBuilder {
+        let mut chain = ChainBuilder::new(rng);
+        chain
+            .extend(rng, false)
+            .extend(rng, false)
+            .extend(rng, true)
+            .extend(rng, false)
+            .extend(rng, true)
+            .extend(rng, false);
+        chain
+    }
+
+    #[test]
+    fn test_verify_block() {
+        let rng = &mut TestRng::new();
+        let block = Block::from(TestBlockBuilder::new().build(rng));
+        verify_block(&block).unwrap();
+        verify_block_hash(&block.clone_header(), block.hash()).unwrap();
+
+        let invalid = Block::from(TestBlockBuilder::new().build_invalid(rng));
+        assert!(matches!(
+            verify_block(&invalid),
+            Err(ChainError::InvalidBlock(_))
+        ));
+        assert!(matches!(
+            verify_block_hash(&block.clone_header(), invalid.hash()),
+            Err(ChainError::BlockHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_header_chain() {
+        let rng = &mut TestRng::new();
+        let chain = get_chain(rng);
+        let headers = chain.headers();
+        verify_header_chain(&headers).unwrap();
+
+        let mut verifier = HeaderChainVerifier::new(headers[0].clone());
+        assert_eq!(
+            verifier.validator_weights(EraId::new(1)),
+            Some(&chain.weights())
+        );
+        verifier.verify_all(&headers[1..]).unwrap();
+        assert_eq!(verifier.latest(), headers.last().unwrap());
+        assert_eq!(
+            verifier.validator_weights(EraId::new(3)),
+            Some(&chain.weights())
+        );
+        assert_eq!(verifier.validator_weights(EraId::new(2)), None);
+    }
+
+    #[test]
+    fn test_reject_broken_linkage() {
+        let rng = &mut TestRng::new();
+        let headers = get_chain(rng).headers();
+
+        // Gap within the chain.
+        let mut gapped = headers.clone();
+        gapped.remove(2);
+        assert!(matches!(
+            verify_header_chain(&gapped),
+            Err(ChainError::HeightMismatch {
+                expected: 2,
+                got: 3
+            })
+        ));
+
+        // Header from another chain at the expected height.
+        let other = get_chain(rng).headers();
+        let mut forked = headers.clone();
+        forked[2] = other[2].clone();
+        assert!(matches!(
+            verify_header_chain(&forked),
+            Err(ChainError::ParentHashMismatch { height: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_reject_unknown_proposer() {
+        let rng = &mut TestRng::new();
+        let mut chain = ChainBuilder::new(rng);
+        chain.extend(rng, false);
+        let parent = chain.blocks.last().unwrap().header().clone();
+        let outsider = PublicKey::random(rng);
+        chain.push(
+            rng,
+            parent.block_hash(),
+            parent.era_id(),
+            parent.height() + 1,
+            false,
+            outsider,
+        );
+
+        assert!(matches!(
+            verify_header_chain(&chain.headers()),
+            Err(ChainError::UnknownProposer { height: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_reject_switch_block_mismatch() {
+        let rng = &mut TestRng::new();
+        let mut chain = ChainBuilder::new(rng);
+        chain.extend(rng, false);
+        // Claims another switch block as the last.
+        chain.last_switch_block_hash = Some(BlockHash::random(rng));
+        chain.extend(rng, false);
+
+        assert!(matches!(
+            verify_header_chain(&chain.headers()),
+            Err(ChainError::LastSwitchBlockMismatch { height: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,428 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::crypto::{
+        merkle::{
+            compute_state_root_hash, decode_hex_proofs, named_dictionary_key,
+            verify_dictionary_query_result, verify_proof, verify_proofs, verify_query_result,
+            StateProof,
+        },
+        ProofError,
+    };
+    use casper_types::{
+        account::{Account, AccountHash},
+        bytesrepr::{self, ToBytes},
+        global_state::{Pointer, TrieMerkleProof, TrieMerkleProofStep},
+        AccessRights, CLValue, CLValueDictionary, Digest, Key, NamedKeys, StoredValue, URef, U512,
+    };
+    use l1_binary_port::{DictionaryQueryResult, GlobalStateQueryResult};
+    use std::collections::VecDeque;
+
+    fn cl_value(value: u64) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(value).unwrap())
+    }
+
+    fn leaf_pointer(key: Key, value: StoredValue) -> Pointer {
+        let leaf = TrieMerkleProof::new(key, value, VecDeque::new());
+        Pointer::LeafPointer(compute_state_root_hash(&leaf).unwrap())
+    }
+
+    /// Proof of an entry whose leaf shares a node with another leaf, beneath an extension.
+    fn proof(
+        key: Key,
+        value: StoredValue,
+        hole_index: u8,
+        sibling_index: u8,
+        sibling: Pointer,
+    ) -> StateProof {
+        TrieMerkleProof::new(
+            key,
+            value,
+            VecDeque::from([
+                TrieMerkleProofStep::node(hole_index, vec![(sibling_index, sibling)]),
+                TrieMerkleProofStep::extension(vec![1, 2, 3]),
+            ]),
+        )
+    }
+
+    /// Proofs of entries held as leaves of a single node, all sharing its hash as state root hash.
+    fn node_proofs(entries: &[(Key, StoredValue)]) -> (Vec<StateProof>, Digest) {
+        let pointers: Vec<(u8, Pointer)> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, (key, value))| (index as u8, leaf_pointer(*key, value.clone())))
+            .collect();
+        let proofs: Vec<StateProof> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, (key, value))| {
+                let siblings = pointers
+                    .iter()
+                    .filter(|(sibling_index, _)| *sibling_index as usize != index)
+                    .cloned()
+                    .collect();
+                TrieMerkleProof::new(
+                    *key,
+                    value.clone(),
+                    VecDeque::from([TrieMerkleProofStep::node(index as u8, siblings)]),
+                )
+            })
+            .collect();
+        let state_root_hash = compute_state_root_hash(&proofs[0]).unwrap();
+        (proofs, state_root_hash)
+    }
+
+    fn account(account_hash: AccountHash, named_keys: &[(&str, Key)]) -> StoredValue {
+        let mut keys = NamedKeys::new();
+        for (name, key) in named_keys {
+            keys.insert(name.to_string(), *key);
+        }
+        let main_purse = URef::new([10; 32], AccessRights::READ_ADD_WRITE);
+        StoredValue::Account(Account::create(account_hash, keys, main_purse))
+    }
+
+    #[test]
+    fn test_leaf_hash() {
+        let key = Key::Hash([1; 32]);
+        let value = cl_value(7);
+        let leaf = TrieMerkleProof::new(key, value.clone(), VecDeque::new());
+
+        let mut bytes = vec![0];
+        bytes.extend(key.to_bytes().unwrap());
+        bytes.extend(value.to_bytes().unwrap());
+        assert_eq!(compute_state_root_hash(&leaf).unwrap(), Digest::hash(bytes));
+    }
+
+    #[test]
+    fn test_proofs_of_siblings_share_state_root_hash() {
+        let (key_a, value_a) = (Key::Hash([1; 32]), cl_value(1));
+        let (key_b, value_b) = (Key::Hash([2; 32]), cl_value(2));
+        let proof_a = proof(
+            key_a,
+            value_a.clone(),
+            1,
+            2,
+            leaf_pointer(key_b, value_b.clone()),
+        );
+        let proof_b = proof(key_b, value_b, 2, 1, leaf_pointer(key_a, value_a));
+
+        let state_root_hash = compute_state_root_hash(&proof_a).unwrap();
+        assert_eq!(compute_state_root_hash(&proof_b).unwrap(), state_root_hash);
+        verify_proof(&proof_a, &state_root_hash).unwrap();
+        verify_proof(&proof_b, &state_root_hash).unwrap();
+
+        // Swapping positions within the node changes the trie.
+        let swapped = proof(key_a, cl_value(1), 2, 1, leaf_pointer(key_b, cl_value(2)));
+        assert!(matches!(
+            verify_proof(&swapped, &state_root_hash),
+            Err(ProofError::StateRootHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_query_result() {
+        let (key, value) = (Key::Hash([1; 32]), cl_value(1));
+        let sibling = leaf_pointer(Key::Hash([2; 32]), cl_value(2));
+        let valid = proof(key, value.clone(), 1, 2, sibling);
+        let state_root_hash = compute_state_root_hash(&valid).unwrap();
+
+        let result = GlobalStateQueryResult::new(value.clone(), vec![valid.clone()]);
+        assert_eq!(
+            verify_query_result(result, &key, &[], &state_root_hash).unwrap(),
+            value
+        );
+
+        // Node returns a value other than that proven.
+        let result = GlobalStateQueryResult::new(cl_value(9), vec![valid.clone()]);
+        assert!(matches!(
+            verify_query_result(result, &key, &[], &state_root_hash),
+            Err(ProofError::ValueMismatch)
+        ));
+
+        // Node proves a value other than that held.
+        let forged = proof(key, cl_value(9), 1, 2, sibling);
+        assert!(matches!(
+            verify_proofs(&[forged], &key, &[], &cl_value(9), &state_root_hash),
+            Err(ProofError::StateRootHashMismatch { .. })
+        ));
+
+        // Node proves an entry under another key.
+        assert!(matches!(
+            verify_proofs(&[valid], &Key::Hash([3; 32]), &[], &value, &state_root_hash),
+            Err(ProofError::KeyMismatch { .. })
+        ));
+
+        assert!(matches!(
+            verify_proofs(&[], &key, &[], &value, &state_root_hash),
+            Err(ProofError::EmptyProof)
+        ));
+    }
+
+    #[test]
+    fn test_verify_proofs_along_path() {
+        let account_hash = AccountHash::new([1; 32]);
+        let (counter, decoy) = (Key::Hash([2; 32]), Key::Hash([3; 32]));
+        let owner = Key::Account(account_hash);
+        let (proofs, state_root_hash) = node_proofs(&[
+            (owner, account(account_hash, &[("counter", counter)])),
+            (counter, cl_value(7)),
+            (decoy, cl_value(9)),
+        ]);
+        let (account_proof, counter_proof, decoy_proof) = (&proofs[0], &proofs[1], &proofs[2]);
+        let path = ["counter".to_string()];
+
+        verify_proofs(&proofs[..2], &owner, &path, &cl_value(7), &state_root_hash).unwrap();
+
+        // Node splices in another proven entry in place of that which the path names.
+        assert!(matches!(
+            verify_proofs(
+                &[account_proof.clone(), decoy_proof.clone()],
+                &owner,
+                &path,
+                &cl_value(9),
+                &state_root_hash,
+            ),
+            Err(ProofError::KeyMismatch { expected, got })
+                if *expected == counter && *got == decoy
+        ));
+
+        // Node claims a name absent from the named keys.
+        assert!(matches!(
+            verify_proofs(
+                &[account_proof.clone(), decoy_proof.clone()],
+                &owner,
+                &["decoy".to_string()],
+                &cl_value(9),
+                &state_root_hash,
+            ),
+            Err(ProofError::UnresolvedName { name, .. }) if name == "decoy"
+        ));
+
+        // Node stops short of the path, or continues beyond it.
+        assert!(matches!(
+            verify_proofs(
+                &proofs[..1],
+                &owner,
+                &path,
+                account_proof.value(),
+                &state_root_hash,
+            ),
+            Err(ProofError::PathMismatch { .. })
+        ));
+        assert!(matches!(
+            verify_proofs(&proofs[..2], &owner, &[], &cl_value(7), &state_root_hash,),
+            Err(ProofError::PathMismatch { .. })
+        ));
+
+        // A proof of a value which names no further keys cannot continue the path.
+        assert!(matches!(
+            verify_proofs(
+                &[decoy_proof.clone(), counter_proof.clone()],
+                &decoy,
+                &path,
+                &cl_value(7),
+                &state_root_hash,
+            ),
+            Err(ProofError::UnresolvedName { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_proofs_following_key_values() {
+        let (redirect, target, decoy) =
+            (Key::Hash([4; 32]), Key::Hash([5; 32]), Key::Hash([6; 32]));
+        let (proofs, state_root_hash) = node_proofs(&[
+            (
+                redirect,
+                StoredValue::CLValue(CLValue::from_t(target).unwrap()),
+            ),
+            (target, cl_value(1)),
+            (decoy, cl_value(2)),
+        ]);
+
+        // A key stored as a value is followed without consuming a name.
+        let path = ["counter".to_string()];
+        assert!(matches!(
+            verify_proofs(
+                &proofs[..2],
+                &redirect,
+                &path,
+                &cl_value(1),
+                &state_root_hash,
+            ),
+            Err(ProofError::UnresolvedName { key, name }) if *key == target && name == "counter"
+        ));
+        assert!(matches!(
+            verify_proofs(
+                &[proofs[0].clone(), proofs[2].clone()],
+                &redirect,
+                &[],
+                &cl_value(2),
+                &state_root_hash,
+            ),
+            Err(ProofError::PathMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_dictionary_query_result() {
+        let account_hash = AccountHash::new([7; 32]);
+        let owner = Key::Account(account_hash);
+        let seed_uref = URef::new([8; 32], AccessRights::READ_ADD_WRITE);
+        let owner_value = account(account_hash, &[("balances", Key::URef(seed_uref))]);
+        let item_key = Key::dictionary(seed_uref, b"alice");
+        let other_key = Key::dictionary(seed_uref, b"bob");
+        let stored = |value: u64, item: &[u8]| {
+            let dictionary_value = CLValueDictionary::new(
+                CLValue::from_t(value).unwrap(),
+                seed_uref.addr().to_vec(),
+                item.to_vec(),
+            );
+            StoredValue::CLValue(CLValue::from_t(dictionary_value).unwrap())
+        };
+        let (proofs, state_root_hash) = node_proofs(&[
+            (item_key, stored(5, b"alice")),
+            (other_key, stored(6, b"bob")),
+        ]);
+
+        // Key of the item is derived from the named seed URef, not taken from the node.
+        let expected_key = named_dictionary_key(&owner, &owner_value, "balances", "alice").unwrap();
+        assert_eq!(expected_key, item_key);
+        assert!(matches!(
+            named_dictionary_key(&owner, &owner_value, "missing", "alice"),
+            Err(ProofError::UnresolvedName { .. })
+        ));
+
+        // Node returns the item's value unwrapped from the stored dictionary value.
+        let result = |key, proof: &StateProof, value| {
+            DictionaryQueryResult::new(key, GlobalStateQueryResult::new(value, vec![proof.clone()]))
+        };
+        assert_eq!(
+            verify_dictionary_query_result(
+                result(item_key, &proofs[0], cl_value(5)),
+                &expected_key,
+                &state_root_hash,
+            )
+            .unwrap(),
+            (item_key, cl_value(5))
+        );
+
+        // Node answers with another item of the dictionary.
+        assert!(matches!(
+            verify_dictionary_query_result(
+                result(other_key, &proofs[1], cl_value(6)),
+                &expected_key,
+                &state_root_hash,
+            ),
+            Err(ProofError::KeyMismatch { .. })
+        ));
+        assert!(matches!(
+            verify_dictionary_query_result(
+                result(item_key, &proofs[0], cl_value(6)),
+                &expected_key,
+                &state_root_hash,
+            ),
+            Err(ProofError::ValueMismatch)
+        ));
+    }
+
+    /// Proofs returned by casper-storage 2.1.1's `TrackingCopy::query`, the implementation a node
+    /// answers queries with, over an LMDB global state with a known state root hash.
+    const NODE_PROOFS: &str = include_str!("fixtures/merkle_proofs.json");
+
+    #[test]
+    fn test_verify_node_proofs() {
+        let fixture: serde_json::Value = serde_json::from_str(NODE_PROOFS).unwrap();
+        let digest = |hex: &serde_json::Value| {
+            Digest::try_from(base16::decode(hex.as_str().unwrap()).unwrap().as_slice()).unwrap()
+        };
+        let key = |formatted: &serde_json::Value| {
+            Key::from_formatted_str(formatted.as_str().unwrap()).unwrap()
+        };
+        let decode = |hex: &serde_json::Value| base16::decode(hex.as_str().unwrap()).unwrap();
+        let state_root_hash = digest(&fixture["state_root_hash"]);
+
+        let items = fixture["items"].as_array().unwrap();
+        let mut values = Vec::new();
+        for item in items {
+            let path: Vec<String> = serde_json::from_value(item["path"].clone()).unwrap();
+            let result: GlobalStateQueryResult =
+                bytesrepr::deserialize_from_slice(decode(&item["result"])).unwrap();
+            let value =
+                verify_query_result(result, &key(&item["base_key"]), &path, &state_root_hash)
+                    .unwrap();
+            values.push(value);
+        }
+        assert!(matches!(values[0], StoredValue::Account(_)));
+        assert_eq!(values[1], cl_value(42));
+        assert_eq!(values[2], cl_value(42));
+
+        // Proofs of one query don't verify another's path, nor another state.
+        let result: GlobalStateQueryResult =
+            bytesrepr::deserialize_from_slice(decode(&items[1]["result"])).unwrap();
+        assert!(matches!(
+            verify_query_result(
+                result.clone(),
+                &key(&items[1]["base_key"]),
+                &["balances".to_string()],
+                &state_root_hash,
+            ),
+            Err(ProofError::KeyMismatch { .. })
+        ));
+        assert!(matches!(
+            verify_query_result(
+                result,
+                &key(&items[1]["base_key"]),
+                &["counter".to_string()],
+                &Digest::hash(b"other state"),
+            ),
+            Err(ProofError::StateRootHashMismatch { .. })
+        ));
+
+        // Dictionary item's key is derived from the account's named seed URef.
+        let dictionary = &fixture["dictionary_item"];
+        let owner = key(&dictionary["account_hash"]);
+        let dictionary_key = named_dictionary_key(
+            &owner,
+            &values[0],
+            dictionary["dictionary_name"].as_str().unwrap(),
+            dictionary["dictionary_item_key"].as_str().unwrap(),
+        )
+        .unwrap();
+        let result: DictionaryQueryResult =
+            bytesrepr::deserialize_from_slice(decode(&dictionary["result"])).unwrap();
+        let (item_key, value) =
+            verify_dictionary_query_result(result, &dictionary_key, &state_root_hash).unwrap();
+        assert_eq!(item_key, dictionary_key);
+        assert_eq!(
+            value,
+            StoredValue::CLValue(CLValue::from_t(U512::from(1_000u64)).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_decode_hex_proofs() {
+        let proofs = vec![proof(
+            Key::Hash([1; 32]),
+            cl_value(1),
+            1,
+            2,
+            leaf_pointer(Key::Hash([2; 32]), cl_value(2)),
+        )];
+        let hex: String = proofs
+            .to_bytes()
+            .unwrap()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        assert_eq!(decode_hex_proofs(&hex).unwrap(), proofs);
+        assert!(matches!(
+            decode_hex_proofs("zz"),
+            Err(ProofError::HexError(_))
+        ));
+        assert!(matches!(
+            decode_hex_proofs("00"),
+            Err(ProofError::DecodeError(_))
+        ));
+    }
+}
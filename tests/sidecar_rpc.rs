@@ -0,0 +1,860 @@
+#[cfg(test)]
+mod tests {
+    use casper_sdk_rs::api::sidecar::rpc::{
+        error::{InvalidTransactionError, ServerError},
+        types::{
+            AccountIdentifier, BalanceHold, DictionaryIdentifier, Entity, EntityIdentifier,
+            EraIdentifier, EraSummary, PurseIdentifier, Reward,
+        },
+        Client, RpcError,
+    };
+    use casper_types::{
+        account::{Account, AccountHash},
+        bytesrepr::Bytes,
+        execution::ExecutionResult,
+        system::auction::Bid,
+        testing::TestRng,
+        AccessRights, AvailableBlockRange, Block, BlockHash, BlockIdentifier,
+        BlockSynchronizerStatus, BlockTime, CLValue, Deploy, Digest, EntityAddr, EraId,
+        ExecutionInfo, GlobalStateIdentifier, Key, NamedKeys, Peers, ProtocolVersion, PublicKey,
+        SecretKey, StoredValue, TestBlockBuilder, TimeDiff, Timestamp, Transaction, Transfer, URef,
+        ValidatorChange, U512,
+    };
+    use l1_binary_port::ErrorCode;
+    use serde_json::{json, Value};
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+    };
+
+    type Handler = dyn Fn(&Value) -> (u16, Value) + Send + Sync;
+
+    /// Minimal HTTP server answering JSON-RPC requests via a handler, and recording them.
+    struct MockRpc {
+        url: String,
+        requests: Arc<Mutex<Vec<Value>>>,
+    }
+
+    impl MockRpc {
+        async fn start(handler: impl Fn(&Value) -> (u16, Value) + Send + Sync + 'static) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/rpc", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let handler: Arc<Handler> = Arc::new(handler);
+
+            let recorded = requests.clone();
+            tokio::spawn(async move {
+                while let Ok((socket, _)) = listener.accept().await {
+                    let handler = handler.clone();
+                    let recorded = recorded.clone();
+                    tokio::spawn(async move {
+                        let mut socket = BufReader::new(socket);
+                        // Serves requests over the connection until closed.
+                        loop {
+                            let mut content_length = 0;
+                            loop {
+                                let mut line = String::new();
+                                if socket.read_line(&mut line).await.unwrap_or(0) == 0 {
+                                    return;
+                                }
+                                let line = line.trim_end();
+                                if line.is_empty() {
+                                    break;
+                                }
+                                if let Some((name, value)) = line.split_once(':') {
+                                    if name.eq_ignore_ascii_case("content-length") {
+                                        content_length = value.trim().parse().unwrap();
+                                    }
+                                }
+                            }
+                            let mut body = vec![0; content_length];
+                            socket.read_exact(&mut body).await.unwrap();
+                            let request: Value = serde_json::from_slice(&body).unwrap();
+                            let (status, response) = handler(&request);
+                            recorded.lock().unwrap().push(request);
+
+                            let body = response.to_string();
+                            let head = format!(
+                                "HTTP/1.1 {status} OK\r\ncontent-type: application/json\r\n\
+                                 content-length: {}\r\n\r\n",
+                                body.len()
+                            );
+                            let socket = socket.get_mut();
+                            socket.write_all(head.as_bytes()).await.unwrap();
+                            socket.write_all(body.as_bytes()).await.unwrap();
+                        }
+                    });
+                }
+            });
+            MockRpc { url, requests }
+        }
+
+        /// Starts a server answering each method with a result.
+        async fn with_results(results: Vec<(&'static str, Value)>) -> Self {
+            let results: BTreeMap<_, _> = results.into_iter().collect();
+            Self::start(move |request| {
+                let method = request["method"].as_str().unwrap();
+                match results.get(method) {
+                    Some(result) => ok(request, result.clone()),
+                    None => error(request, -32601, "Method not found"),
+                }
+            })
+            .await
+        }
+
+        fn requests(&self) -> Vec<Value> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        fn last_params(&self) -> Option<Value> {
+            self.requests().last().unwrap().get("params").cloned()
+        }
+    }
+
+    fn ok(request: &Value, result: Value) -> (u16, Value) {
+        let response = json!({"jsonrpc": "2.0", "id": request["id"], "result": result});
+        (200, response)
+    }
+
+    fn error(request: &Value, code: i64, message: &str) -> (u16, Value) {
+        let response = json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "error": {"code": code, "message": message},
+        });
+        (200, response)
+    }
+
+    fn api_version() -> Value {
+        json!("2.0.0")
+    }
+
+    #[tokio::test]
+    async fn test_get_status() {
+        let rng = &mut TestRng::new();
+        let block_hash = BlockHash::random(rng);
+        let state_root_hash = Digest::random(rng);
+        let creator = PublicKey::random(rng);
+        let peers = Peers::from(BTreeMap::from([(
+            "tls:0102".to_string(),
+            "1.2.3.4:35000".to_string(),
+        )]));
+        let status = json!({
+            "api_version": api_version(),
+            "protocol_version": "2.0.0",
+            "peers": peers,
+            "chainspec_name": "casper-test",
+            "starting_state_root_hash": Digest::default(),
+            "last_added_block_info": {
+                "hash": block_hash,
+                "timestamp": "2024-07-01T00:00:00.000Z",
+                "era_id": 7,
+                "height": 100,
+                "state_root_hash": state_root_hash,
+                "creator": creator,
+            },
+            "our_public_signing_key": null,
+            "round_length": "1m 5s 536ms",
+            "next_upgrade": null,
+            "uptime": "2h 3m",
+            "reactor_state": "Validate",
+            "last_progress": "2024-07-01T00:00:00.000Z",
+            "available_block_range": AvailableBlockRange::new(0, 100),
+            "block_sync": BlockSynchronizerStatus::default(),
+            "latest_switch_block_hash": block_hash,
+            "build_version": "2.0.0-abc",
+        });
+        let server = MockRpc::with_results(vec![("info_get_status", status)]).await;
+        let client = Client::new(&server.url);
+
+        let status = client.get_status().await.unwrap();
+        assert_eq!(
+            status.protocol_version,
+            ProtocolVersion::from_parts(2, 0, 0)
+        );
+        assert_eq!(status.peers, peers);
+        assert_eq!(status.uptime, TimeDiff::from_seconds(2 * 60 * 60 + 3 * 60));
+        let block_info = status.last_added_block_info.unwrap();
+        assert_eq!(block_info.hash, block_hash);
+        assert_eq!(block_info.era_id, EraId::new(7));
+        assert_eq!(block_info.creator, creator);
+        assert_eq!(
+            block_info.timestamp,
+            "2024-07-01T00:00:00.000Z".parse::<Timestamp>().unwrap()
+        );
+        assert_eq!(status.latest_switch_block_hash, Some(block_hash));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["jsonrpc"], "2.0");
+        assert_eq!(requests[0]["method"], "info_get_status");
+        assert!(requests[0].get("params").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_validator_changes_and_reward() {
+        let rng = &mut TestRng::new();
+        let validator = PublicKey::random(rng);
+        let delegator = PublicKey::from(&SecretKey::random(rng));
+        let switch_block_hash = BlockHash::random(rng);
+        let server = MockRpc::with_results(vec![
+            (
+                "info_get_validator_changes",
+                json!({
+                    "api_version": api_version(),
+                    "changes": [{
+                        "public_key": validator,
+                        "status_changes": [
+                            {"era_id": 1, "validator_change": "Added"},
+                            {"era_id": 5, "validator_change": "Removed"},
+                        ],
+                    }],
+                }),
+            ),
+            (
+                "info_get_reward",
+                json!({
+                    "api_version": api_version(),
+                    "reward_amount": "1000",
+                    "era_id": 4,
+                    "delegation_rate": 10,
+                    "switch_block_hash": switch_block_hash,
+                }),
+            ),
+        ])
+        .await;
+        let client = Client::new(&server.url);
+
+        let changes = client.get_validator_changes().await.unwrap();
+        assert_eq!(
+            changes,
+            BTreeMap::from([(
+                validator.clone(),
+                vec![
+                    (EraId::new(1), ValidatorChange::Added),
+                    (EraId::new(5), ValidatorChange::Removed),
+                ]
+            )])
+        );
+
+        let reward = client
+            .get_reward(
+                Some(EraIdentifier::Era(EraId::new(4))),
+                validator.clone(),
+                Some(delegator.clone()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            reward,
+            Reward {
+                amount: U512::from(1000),
+                era_id: EraId::new(4),
+                delegation_rate: 10,
+                switch_block_hash,
+            }
+        );
+        assert_eq!(
+            server.last_params(),
+            Some(
+                json!({"era_identifier": {"Era": 4}, "validator": validator, "delegator": delegator})
+            )
+        );
+
+        client
+            .get_reward(None, validator.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(server.last_params(), Some(json!({"validator": validator})));
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_and_deploy() {
+        let rng = &mut TestRng::new();
+        let transaction = Transaction::random(rng);
+        let deploy = Deploy::random(rng);
+        let execution_info = ExecutionInfo {
+            block_hash: BlockHash::random(rng),
+            block_height: 10,
+            execution_result: Some(ExecutionResult::random(rng)),
+        };
+        let server = MockRpc::with_results(vec![
+            (
+                "info_get_transaction",
+                json!({
+                    "api_version": api_version(),
+                    "transaction": transaction,
+                    "execution_info": execution_info,
+                }),
+            ),
+            (
+                "info_get_deploy",
+                json!({"api_version": api_version(), "deploy": deploy, "execution_info": null}),
+            ),
+        ])
+        .await;
+        let client = Client::new(&server.url);
+
+        let fetched = client
+            .get_transaction(transaction.hash(), true)
+            .await
+            .unwrap();
+        assert_eq!(fetched.transaction, transaction);
+        assert_eq!(fetched.execution_info, Some(execution_info));
+        assert_eq!(
+            server.last_params(),
+            Some(json!({"transaction_hash": transaction.hash(), "finalized_approvals": true}))
+        );
+
+        let fetched = client.get_deploy(*deploy.hash(), false).await.unwrap();
+        assert_eq!(fetched.deploy, deploy);
+        assert_eq!(fetched.execution_info, None);
+        assert_eq!(
+            server.last_params(),
+            Some(json!({"deploy_hash": deploy.hash(), "finalized_approvals": false}))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chain_queries() {
+        let rng = &mut TestRng::new();
+        let block = Block::from(TestBlockBuilder::new().height(5).build(rng));
+        let secret_key = SecretKey::random(rng);
+        let public_key = PublicKey::from(&secret_key);
+        let signature = casper_types::crypto::sign(block.hash().inner(), &secret_key, &public_key);
+        let transfers = vec![Transfer::random(rng), Transfer::random(rng)];
+        let era_summary = json!({
+            "block_hash": block.hash(),
+            "era_id": 1,
+            "stored_value": StoredValue::CLValue(CLValue::from_t(1_u64).unwrap()),
+            "state_root_hash": block.state_root_hash(),
+            "merkle_proof": "01",
+        });
+        let server = MockRpc::with_results(vec![
+            (
+                "chain_get_block",
+                json!({
+                    "api_version": api_version(),
+                    "block_with_signatures": {
+                        "block": block,
+                        "proofs": [{"public_key": public_key, "signature": signature}],
+                    },
+                }),
+            ),
+            (
+                "chain_get_block_transfers",
+                json!({
+                    "api_version": api_version(),
+                    "block_hash": block.hash(),
+                    "transfers": transfers,
+                }),
+            ),
+            (
+                "chain_get_state_root_hash",
+                json!({"api_version": api_version(), "state_root_hash": block.state_root_hash()}),
+            ),
+            (
+                "chain_get_era_summary",
+                json!({"api_version": api_version(), "era_summary": era_summary}),
+            ),
+            (
+                "chain_get_era_info_by_switch_block",
+                json!({"api_version": api_version(), "era_summary": null}),
+            ),
+        ])
+        .await;
+        let client = Client::new(&server.url);
+
+        let fetched = client
+            .get_block(Some(BlockIdentifier::Height(5)))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(fetched.block, block);
+        assert_eq!(fetched.proofs, BTreeMap::from([(public_key, signature)]));
+        assert_eq!(
+            server.last_params(),
+            Some(json!({"block_identifier": {"Height": 5}}))
+        );
+
+        let fetched = client
+            .get_block_transfers(Some(BlockIdentifier::Hash(*block.hash())))
+            .await
+            .unwrap();
+        assert_eq!(fetched.block_hash, Some(*block.hash()));
+        assert_eq!(fetched.transfers, Some(transfers));
+        assert_eq!(
+            server.last_params(),
+            Some(json!({"block_identifier": {"Hash": block.hash()}}))
+        );
+
+        assert_eq!(
+            client.get_state_root_hash(None).await.unwrap(),
+            Some(*block.state_root_hash())
+        );
+        assert_eq!(server.last_params(), None);
+
+        let EraSummary {
+            era_id,
+            stored_value,
+            ..
+        } = client.get_era_summary(None).await.unwrap();
+        assert_eq!(era_id, EraId::new(1));
+        assert!(matches!(stored_value, StoredValue::CLValue(_)));
+
+        assert_eq!(
+            client.get_era_info_by_switch_block(None).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let server = MockRpc::start(|request| match request["method"].as_str().unwrap() {
+            "info_get_peers" => error(request, -32001, "No such block"),
+            "info_get_chainspec" => ok(&json!({"id": 999}), json!({})),
+            "chain_get_block" => ok(request, json!({"block_with_signatures": 5})),
+            _ => (500, json!("internal error")),
+        })
+        .await;
+        let client = Client::new(&server.url);
+
+        let err = client.get_peers().await.unwrap_err();
+        assert!(matches!(
+            err,
+            RpcError::Server(ServerError { code: -32001, .. })
+        ));
+        assert_eq!(err.to_string(), "Server error: No such block (code -32001)");
+
+        assert!(matches!(
+            client.get_chainspec().await,
+            Err(RpcError::IdMismatch { got, .. }) if got == 999
+        ));
+        assert!(matches!(
+            client.get_block(None).await,
+            Err(RpcError::SerializationError(_))
+        ));
+        assert!(matches!(
+            client.get_status().await,
+            Err(RpcError::Transport(_))
+        ));
+
+        // Each request bears its own id.
+        let ids: Vec<_> = server
+            .requests()
+            .iter()
+            .map(|req| req["id"].clone())
+            .collect();
+        assert_eq!(ids, vec![json!(0), json!(1), json!(2), json!(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_query_global_state_and_balances() {
+        let rng = &mut TestRng::new();
+        let state_root_hash = Digest::random(rng);
+        let stored_value = StoredValue::CLValue(CLValue::from_t(7_u64).unwrap());
+        let purse = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        let server = MockRpc::with_results(vec![
+            (
+                "query_global_state",
+                json!({
+                    "api_version": api_version(),
+                    "block_header": null,
+                    "stored_value": stored_value,
+                    "merkle_proof": "0102",
+                }),
+            ),
+            (
+                "state_get_item",
+                json!({"api_version": api_version(), "stored_value": stored_value, "merkle_proof": "03"}),
+            ),
+            (
+                "query_balance",
+                json!({"api_version": api_version(), "balance": "100"}),
+            ),
+            (
+                "query_balance_details",
+                json!({
+                    "api_version": api_version(),
+                    "total_balance": "100",
+                    "available_balance": "60",
+                    "total_balance_proof": "04",
+                    "holds": [{"time": 1000, "amount": "40", "proof": "05"}],
+                }),
+            ),
+            (
+                "state_get_balance",
+                json!({"api_version": api_version(), "balance_value": "100", "merkle_proof": "06"}),
+            ),
+        ])
+        .await;
+        let client = Client::new(&server.url);
+
+        let key = Key::Hash([1; 32]);
+        let fetched = client
+            .query_global_state(
+                Some(GlobalStateIdentifier::StateRootHash(state_root_hash)),
+                key,
+                vec!["counter".to_string()],
+            )
+            .await
+            .unwrap();
+        assert_eq!(fetched.block_header, None);
+        assert_eq!(fetched.stored_value, stored_value);
+        assert_eq!(fetched.merkle_proof, "0102");
+        assert_eq!(
+            server.last_params(),
+            Some(json!({
+                "state_identifier": {"StateRootHash": state_root_hash},
+                "key": key.to_formatted_string(),
+                "path": ["counter"],
+            }))
+        );
+
+        let fetched = client.get_item(state_root_hash, key, vec![]).await.unwrap();
+        assert_eq!(fetched.stored_value, stored_value);
+        assert_eq!(
+            server.last_params(),
+            Some(
+                json!({"state_root_hash": state_root_hash, "key": key.to_formatted_string(), "path": []})
+            )
+        );
+
+        let balance = client
+            .query_balance(None, PurseIdentifier::PurseUref(purse))
+            .await
+            .unwrap();
+        assert_eq!(balance, U512::from(100));
+        assert_eq!(
+            server.last_params(),
+            Some(json!({"purse_identifier": {"PurseUref": purse.to_formatted_string()}}))
+        );
+
+        let public_key = PublicKey::random(rng);
+        let details = client
+            .query_balance_details(
+                Some(GlobalStateIdentifier::BlockHeight(10)),
+                PurseIdentifier::MainPurseUnderPublicKey(public_key.clone()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(details.total_balance, U512::from(100));
+        assert_eq!(details.available_balance, U512::from(60));
+        assert_eq!(
+            details.holds,
+            vec![BalanceHold {
+                time: BlockTime::new(1000),
+                amount: U512::from(40),
+                proof: "05".to_string(),
+            }]
+        );
+        assert_eq!(
+            server.last_params(),
+            Some(json!({
+                "state_identifier": {"BlockHeight": 10},
+                "purse_identifier": {"MainPurseUnderPublicKey": public_key},
+            }))
+        );
+
+        let fetched = client.get_balance(state_root_hash, purse).await.unwrap();
+        assert_eq!(fetched.balance, U512::from(100));
+        assert_eq!(
+            server.last_params(),
+            Some(
+                json!({"state_root_hash": state_root_hash, "purse_uref": purse.to_formatted_string()})
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_dictionary_item() {
+        let rng = &mut TestRng::new();
+        let state_root_hash = Digest::random(rng);
+        let dictionary_key = Key::Dictionary([9; 32]);
+        let stored_value = StoredValue::CLValue(CLValue::from_t("value").unwrap());
+        let server = MockRpc::with_results(vec![(
+            "state_get_dictionary_item",
+            json!({
+                "api_version": api_version(),
+                "dictionary_key": dictionary_key.to_formatted_string(),
+                "stored_value": stored_value,
+                "merkle_proof": "01",
+            }),
+        )])
+        .await;
+        let client = Client::new(&server.url);
+
+        let seed_uref = URef::new([4; 32], AccessRights::READ_ADD_WRITE);
+        let named_key = |key: Key| json!({"key": key.to_formatted_string(), "dictionary_name": "names", "dictionary_item_key": "alice"});
+        let cases = [
+            (
+                DictionaryIdentifier::AccountNamedKey {
+                    hash: AccountHash::new([1; 32]),
+                    dictionary_name: "names".to_string(),
+                    dictionary_item_key: "alice".to_string(),
+                },
+                json!({"AccountNamedKey": named_key(Key::Account(AccountHash::new([1; 32])))}),
+            ),
+            (
+                DictionaryIdentifier::ContractNamedKey {
+                    hash: [2; 32],
+                    dictionary_name: "names".to_string(),
+                    dictionary_item_key: "alice".to_string(),
+                },
+                json!({"ContractNamedKey": named_key(Key::Hash([2; 32]))}),
+            ),
+            (
+                DictionaryIdentifier::EntityNamedKey {
+                    addr: EntityAddr::SmartContract([3; 32]),
+                    dictionary_name: "names".to_string(),
+                    dictionary_item_key: "alice".to_string(),
+                },
+                json!({"EntityNamedKey": named_key(Key::AddressableEntity(EntityAddr::SmartContract([3; 32])))}),
+            ),
+            (
+                DictionaryIdentifier::URef {
+                    seed_uref,
+                    dictionary_item_key: "alice".to_string(),
+                },
+                json!({"URef": {"seed_uref": seed_uref.to_formatted_string(), "dictionary_item_key": "alice"}}),
+            ),
+            (
+                DictionaryIdentifier::Dictionary([9; 32]),
+                json!({"Dictionary": dictionary_key.to_formatted_string()}),
+            ),
+        ];
+        for (dictionary_identifier, expected) in cases {
+            let item = client
+                .get_dictionary_item(state_root_hash, dictionary_identifier)
+                .await
+                .unwrap();
+            assert_eq!(item.dictionary_key, dictionary_key);
+            assert_eq!(item.stored_value, stored_value);
+            assert_eq!(
+                server.last_params(),
+                Some(
+                    json!({"state_root_hash": state_root_hash, "dictionary_identifier": expected})
+                )
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_account_entity_and_auction_info() {
+        let rng = &mut TestRng::new();
+        let public_key = PublicKey::random(rng);
+        let account = Account::create(
+            public_key.to_account_hash(),
+            NamedKeys::new(),
+            URef::new([5; 32], AccessRights::READ_ADD_WRITE),
+        );
+        let bid = Bid::unlocked(
+            public_key.clone(),
+            URef::new([6; 32], AccessRights::READ_ADD_WRITE),
+            U512::from(1000),
+            5,
+        );
+        let server = MockRpc::with_results(vec![
+            (
+                "state_get_account_info",
+                json!({"api_version": api_version(), "account": account, "merkle_proof": "01"}),
+            ),
+            (
+                "state_get_entity",
+                json!({"api_version": api_version(), "entity": {"Account": account}, "merkle_proof": "02"}),
+            ),
+            (
+                "state_get_auction_info",
+                json!({
+                    "api_version": api_version(),
+                    "auction_state": {
+                        "state_root_hash": Digest::default(),
+                        "block_height": 10,
+                        "era_validators": [{
+                            "era_id": 2,
+                            "validator_weights": [{"public_key": public_key, "weight": "1000"}],
+                        }],
+                        "bids": [{"public_key": public_key, "bid": bid}],
+                    },
+                }),
+            ),
+            (
+                "state_get_trie",
+                json!({"api_version": api_version(), "maybe_trie_bytes": "00ff"}),
+            ),
+        ])
+        .await;
+        let client = Client::new(&server.url);
+
+        let fetched = client
+            .get_account_info(AccountIdentifier::PublicKey(public_key.clone()), None)
+            .await
+            .unwrap();
+        assert_eq!(fetched.account, account);
+        assert_eq!(
+            server.last_params(),
+            Some(json!({"account_identifier": public_key}))
+        );
+
+        let fetched = client
+            .get_entity(
+                EntityIdentifier::AccountHash(public_key.to_account_hash()),
+                Some(BlockIdentifier::Height(10)),
+                true,
+            )
+            .await
+            .unwrap();
+        assert_eq!(fetched.entity, Entity::Account(account));
+        assert_eq!(
+            server.last_params(),
+            Some(json!({
+                "entity_identifier": {"AccountHash": public_key.to_account_hash()},
+                "block_identifier": {"Height": 10},
+                "include_bytecode": true,
+            }))
+        );
+
+        let auction_state = client.get_auction_info(None).await.unwrap();
+        assert_eq!(auction_state.block_height, 10);
+        assert_eq!(
+            auction_state.era_validators,
+            BTreeMap::from([(
+                EraId::new(2),
+                BTreeMap::from([(public_key.clone(), U512::from(1000))])
+            )])
+        );
+        assert_eq!(auction_state.bids, BTreeMap::from([(public_key, bid)]));
+
+        let trie_key = Digest::random(rng);
+        let trie_bytes = client.get_trie(trie_key).await.unwrap();
+        assert_eq!(trie_bytes, Some(Bytes::from(vec![0, 255])));
+        assert_eq!(server.last_params(), Some(json!({"trie_key": trie_key})));
+    }
+
+    #[tokio::test]
+    async fn test_put_transaction_and_deploy() {
+        let rng = &mut TestRng::new();
+        let transaction = Transaction::random(rng);
+        let deploy = Deploy::random(rng);
+        let server = MockRpc::with_results(vec![
+            (
+                "account_put_transaction",
+                json!({"api_version": api_version(), "transaction_hash": transaction.hash()}),
+            ),
+            (
+                "account_put_deploy",
+                json!({"api_version": api_version(), "deploy_hash": deploy.hash()}),
+            ),
+        ])
+        .await;
+        let client = Client::new(&server.url);
+
+        let transaction_hash = client.put_transaction(transaction.clone()).await.unwrap();
+        assert_eq!(transaction_hash, transaction.hash());
+        assert_eq!(
+            server.last_params(),
+            Some(json!({"transaction": transaction}))
+        );
+
+        let deploy_hash = client.put_deploy(deploy.clone()).await.unwrap();
+        assert_eq!(deploy_hash, *deploy.hash());
+        assert_eq!(server.last_params(), Some(json!({"deploy": deploy})));
+    }
+
+    #[tokio::test]
+    async fn test_put_transaction_rejected() {
+        let rejections = [
+            (
+                json!({
+                    "code": -32016,
+                    "message": "Invalid transaction",
+                    "data": "the transaction was invalid: Transaction has expired",
+                }),
+                Some(ErrorCode::TransactionExpired),
+            ),
+            (
+                json!({
+                    "code": -32016,
+                    "message": "Invalid transaction",
+                    "data": "the transaction was invalid: The transaction sent to the network had an invalid chain name",
+                }),
+                Some(ErrorCode::InvalidTransactionChainName),
+            ),
+            (
+                json!({
+                    "code": -32008,
+                    "message": "Invalid deploy",
+                    "data": "the deploy was invalid: The deploy had an invalid approval",
+                }),
+                Some(ErrorCode::InvalidDeployApproval),
+            ),
+            // Reason merely mentioned, or described as for the other kind of submission.
+            (
+                json!({
+                    "code": -32016,
+                    "message": "Invalid transaction",
+                    "data": "the transaction was invalid: unknown, not insufficient balance",
+                }),
+                None,
+            ),
+            (
+                json!({
+                    "code": -32008,
+                    "message": "Invalid deploy",
+                    "data": "the transaction was invalid: Transaction has expired",
+                }),
+                None,
+            ),
+            (
+                json!({"code": -32016, "message": "Invalid transaction"}),
+                None,
+            ),
+        ];
+        let errors: Vec<_> = rejections.iter().map(|(error, _)| error.clone()).collect();
+        // Answers each request, by id, with an error, then errors other than rejections.
+        let server = MockRpc::start(move |request| {
+            let id = request["id"].as_u64().unwrap() as usize;
+            let error = errors
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| match id - errors.len() {
+                    0 => json!({
+                        "code": -32099,
+                        "message": "Request failed",
+                        "data": "the transaction was invalid: insufficient balance",
+                    }),
+                    _ => json!({"code": -32603, "message": "Internal error"}),
+                });
+            (
+                200,
+                json!({"jsonrpc": "2.0", "id": request["id"], "error": error}),
+            )
+        })
+        .await;
+        let client = Client::new(&server.url);
+        let rng = &mut TestRng::new();
+
+        for (_, expected) in &rejections {
+            match client.put_transaction(Transaction::random(rng)).await {
+                Err(RpcError::InvalidTransaction(InvalidTransactionError { reason, .. })) => {
+                    assert_eq!(reason, *expected)
+                }
+                result => panic!("unexpected result: {result:?}"),
+            }
+        }
+
+        // Errors other than rejection are left as is, even if describing a reason for one.
+        assert!(matches!(
+            client.put_deploy(Deploy::random(rng)).await,
+            Err(RpcError::Server(ServerError { code: -32099, .. }))
+        ));
+        assert!(matches!(
+            client.put_deploy(Deploy::random(rng)).await,
+            Err(RpcError::Server(ServerError { code: -32603, .. }))
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod utils;
+mod tests {
+    use crate::utils::MockSse;
+    use casper_sdk_rs::api::node::sse::ces::{
+        CesError, ContractEvent, Decoder, Schema, Schemas, TransactionMessages,
+    };
+    use casper_sdk_rs::api::node::sse::{client::Client, SseData};
+    use casper_types::{
+        account::AccountHash,
+        bytesrepr::{Bytes, ToBytes},
+        contract_messages::{Message, MessagePayload, TopicNameHash},
+        BlockHash, CLType, Digest, EntityAddr, Key, TransactionHash, TransactionV1Hash, U512,
+    };
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    const CONTRACT_ADDR: EntityAddr = EntityAddr::SmartContract([7; 32]);
+
+    fn get_schemas() -> Schemas {
+        Schemas::new().with_event(
+            "Transfer",
+            Schema::new()
+                .with_field("recipient", CLType::Key)
+                .with_field("amount", CLType::U512)
+                .with_field("memo", CLType::Option(Box::new(CLType::String))),
+        )
+    }
+
+    fn get_transfer_payload(amount: u64) -> Bytes {
+        let mut bytes = "event_Transfer".to_string().to_bytes().unwrap();
+        bytes.extend(Key::Account(AccountHash::new([1; 32])).to_bytes().unwrap());
+        bytes.extend(U512::from(amount).to_bytes().unwrap());
+        bytes.extend(Some("hello".to_string()).to_bytes().unwrap());
+        bytes.into()
+    }
+
+    fn get_message(entity_addr: EntityAddr, topic_name: &str, payload: MessagePayload) -> Message {
+        Message::new(
+            entity_addr,
+            payload,
+            topic_name.to_string(),
+            TopicNameHash::new([0; 32]),
+            0,
+            0,
+        )
+    }
+
+    fn get_transaction_processed_event(messages: Vec<Message>) -> SseData {
+        SseData::TransactionProcessed(serde_json::json!({
+            "transaction_hash": TransactionHash::V1(TransactionV1Hash::from_raw([2; 32])),
+            "block_hash": BlockHash::new(Digest::from_raw([3; 32])),
+            "messages": messages,
+        }))
+    }
+
+    #[test]
+    fn test_schemas_from_raw_bytes() {
+        // Map of 1 entry: "Transfer" -> [("amount", U512)].
+        let mut bytes = 1_u32.to_bytes().unwrap();
+        bytes.extend("Transfer".to_string().to_bytes().unwrap());
+        bytes.extend(1_u32.to_bytes().unwrap());
+        bytes.extend("amount".to_string().to_bytes().unwrap());
+        bytes.push(8); // CLType::U512 tag
+
+        let schemas = Schemas::from_raw_bytes(&bytes).unwrap();
+        assert_eq!(
+            schemas,
+            Schemas::new().with_event("Transfer", Schema::new().with_field("amount", CLType::U512))
+        );
+    }
+
+    #[test]
+    fn test_transaction_messages_from_event() {
+        let event = get_transaction_processed_event(vec![
+            get_message(CONTRACT_ADDR, "__events", get_transfer_payload(1).into()),
+            get_message(CONTRACT_ADDR, "other", MessagePayload::String("hi".into())),
+        ]);
+        let messages = TransactionMessages::from_event(&event).unwrap();
+        assert_eq!(messages.messages.len(), 2);
+
+        let not_processed = SseData::BlockAdded(serde_json::json!({}));
+        assert!(matches!(
+            TransactionMessages::from_event(&not_processed),
+            Err(CesError::UnexpectedEventType)
+        ));
+    }
+
+    #[test]
+    fn test_decoder_decode_event() {
+        let event = get_transaction_processed_event(vec![
+            get_message(CONTRACT_ADDR, "__events", get_transfer_payload(42).into()),
+            get_message(CONTRACT_ADDR, "other", MessagePayload::String("hi".into())),
+            get_message(
+                EntityAddr::SmartContract([8; 32]),
+                "__events",
+                get_transfer_payload(1).into(),
+            ),
+        ]);
+        let decoder = Decoder::new(CONTRACT_ADDR, get_schemas());
+
+        let events = decoder.decode_event(&event).unwrap();
+        assert_eq!(events.len(), 1, "Only messages of target contract/topic");
+
+        let event = &events[0];
+        assert_eq!(event.name, "Transfer");
+        assert_eq!(event.entity_addr, CONTRACT_ADDR);
+        assert_eq!(
+            event.field("amount").unwrap().to_t::<U512>().unwrap(),
+            U512::from(42)
+        );
+        assert_eq!(
+            event
+                .field("memo")
+                .unwrap()
+                .to_t::<Option<String>>()
+                .unwrap(),
+            Some("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decoder_decode_payload_errors() {
+        let decoder = Decoder::new(CONTRACT_ADDR, get_schemas());
+
+        let bytes = "Transfer".to_string().to_bytes().unwrap();
+        assert!(matches!(
+            decoder.decode_payload(&bytes),
+            Err(CesError::InvalidEventName(_))
+        ));
+
+        let bytes = "event_Approval".to_string().to_bytes().unwrap();
+        assert!(matches!(
+            decoder.decode_payload(&bytes),
+            Err(CesError::EventNotInSchema(_))
+        ));
+
+        let bytes = get_transfer_payload(1);
+        assert!(matches!(
+            decoder.decode_payload(&bytes[..bytes.len() - 1]),
+            Err(CesError::InvalidField(_, _))
+        ));
+
+        let mut bytes = get_transfer_payload(1).to_vec();
+        bytes.push(0);
+        assert!(matches!(
+            decoder.decode_payload(&bytes),
+            Err(CesError::TrailingBytes(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_client_on_contract_event() {
+        let mock_server = MockSse::start().await;
+        let mut client = Client::new(&mock_server.url()).await;
+        client.connect().await.unwrap();
+
+        let (tx, mut rx) = mpsc::channel(2);
+        client
+            .on_contract_event(
+                Decoder::new(CONTRACT_ADDR, get_schemas()),
+                move |event: Result<ContractEvent, CesError>| {
+                    tx.try_send(event.unwrap()).unwrap();
+                },
+            )
+            .await
+            .unwrap();
+
+        mock_server
+            .send_event(get_transaction_processed_event(vec![
+                get_message(CONTRACT_ADDR, "__events", get_transfer_payload(1).into()),
+                get_message(CONTRACT_ADDR, "__events", get_transfer_payload(2).into()),
+            ]))
+            .await
+            .unwrap();
+
+        for amount in 1..=2 {
+            let event = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+                .await
+                .expect("Should receive contract event")
+                .unwrap();
+            assert_eq!(
+                event.field("amount").unwrap().to_t::<U512>().unwrap(),
+                U512::from(amount)
+            );
+        }
+    }
+}
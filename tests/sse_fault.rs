@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod utils;
+mod tests {
+    use crate::utils::MockSse;
+    use casper_sdk_rs::api::node::sse::fault::{Fault, FaultError, FaultMonitor};
+    use casper_sdk_rs::api::node::sse::{client::Client, SseData};
+    use casper_types::{EraId, PublicKey, SecretKey, Timestamp};
+    use std::time::Duration;
+
+    fn get_public_key(seed: u8) -> PublicKey {
+        PublicKey::from(&SecretKey::ed25519_from_bytes([seed; 32]).unwrap())
+    }
+
+    fn get_fault_event(era_id: u64, public_key: PublicKey) -> SseData {
+        SseData::Fault(serde_json::json!({
+            "era_id": EraId::new(era_id),
+            "public_key": public_key,
+            "timestamp": Timestamp::from(1_700_000_000_000),
+        }))
+    }
+
+    #[test]
+    fn test_fault_from_event() {
+        let fault = Fault::from_event(&get_fault_event(4, get_public_key(1))).unwrap();
+        assert_eq!(fault.era_id, EraId::new(4));
+        assert_eq!(fault.public_key, get_public_key(1));
+        assert_eq!(fault.timestamp, Timestamp::from(1_700_000_000_000));
+
+        let step = SseData::Step(serde_json::json!({}));
+        assert!(matches!(
+            Fault::from_event(&step),
+            Err(FaultError::UnexpectedEventType)
+        ));
+    }
+
+    #[test]
+    fn test_fault_monitor_record() {
+        let monitor = FaultMonitor::with_validators([get_public_key(1)]);
+        let fault = |era_id, seed| {
+            Fault::from_event(&get_fault_event(era_id, get_public_key(seed))).unwrap()
+        };
+
+        assert!(monitor.record(fault(1, 2)).is_none(), "Unwatched validator");
+        let alert = monitor.record(fault(1, 1)).expect("Watched validator");
+        assert_eq!(alert.faults_in_era, 2);
+
+        assert!(monitor.watch(get_public_key(2)));
+        assert!(monitor.record(fault(2, 2)).is_some());
+        assert!(monitor.unwatch(&get_public_key(2)));
+        assert!(monitor.record(fault(3, 2)).is_none());
+
+        assert_eq!(monitor.faults_in_era(EraId::new(1)), 2);
+        assert_eq!(monitor.faults_in_era(EraId::new(2)), 1);
+        assert_eq!(monitor.faults_in_era(EraId::new(3)), 1);
+        assert_eq!(monitor.faults_in_era(EraId::new(4)), 0);
+        assert_eq!(monitor.faults_per_era().len(), 3);
+    }
+
+    #[test]
+    fn test_fault_monitor_ignores_redelivered_faults() {
+        let monitor = FaultMonitor::with_validators([get_public_key(1)]);
+        let fault = |era_id, seed| {
+            Fault::from_event(&get_fault_event(era_id, get_public_key(seed))).unwrap()
+        };
+
+        assert!(monitor.record(fault(1, 1)).is_some());
+        assert!(monitor.record(fault(1, 1)).is_none(), "Redelivered fault");
+        assert!(monitor.record(fault(1, 2)).is_none());
+        assert!(monitor.record(fault(1, 2)).is_none());
+        assert_eq!(monitor.faults_in_era(EraId::new(1)), 2);
+
+        let alert = monitor
+            .record(fault(2, 1))
+            .expect("Fault within another era");
+        assert_eq!(alert.faults_in_era, 1);
+    }
+
+    #[test]
+    fn test_fault_monitor_prunes_eras_outside_window() {
+        let monitor = FaultMonitor::with_era_window(3);
+        monitor.watch(get_public_key(1));
+        let fault = |era_id, seed| {
+            Fault::from_event(&get_fault_event(era_id, get_public_key(seed))).unwrap()
+        };
+
+        for era_id in 1..=5 {
+            monitor.record(fault(era_id, 2));
+        }
+        assert_eq!(
+            monitor.faults_per_era().into_keys().collect::<Vec<_>>(),
+            [3, 4, 5].map(EraId::new)
+        );
+
+        assert!(monitor.record(fault(2, 1)).is_none(), "Pruned era");
+        assert_eq!(monitor.faults_in_era(EraId::new(2)), 0);
+        assert!(monitor.record(fault(3, 1)).is_some());
+        assert_eq!(monitor.faults_in_era(EraId::new(3)), 2);
+    }
+
+    #[tokio::test]
+    async fn test_client_validator_fault_channel() {
+        let mock_server = MockSse::start().await;
+        let mut client = Client::new(&mock_server.url()).await;
+        client.connect().await.unwrap();
+
+        let monitor = FaultMonitor::with_validators([get_public_key(1)]);
+        let (_handler_id, mut rx) = client
+            .validator_fault_channel(monitor.clone(), 4)
+            .await
+            .unwrap();
+
+        for seed in [2, 1] {
+            mock_server
+                .send_event(get_fault_event(5, get_public_key(seed)))
+                .await
+                .unwrap();
+        }
+
+        let alert = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("Should receive fault alert")
+            .unwrap()
+            .unwrap();
+        assert_eq!(alert.fault.public_key, get_public_key(1));
+        assert_eq!(alert.faults_in_era, 2);
+        assert_eq!(monitor.faults_in_era(EraId::new(5)), 2);
+    }
+}
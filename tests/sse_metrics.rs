@@ -0,0 +1,85 @@
+#![cfg(feature = "metrics")]
+
+mod utils;
+mod tests {
+    use crate::utils::MockSse;
+    use casper_sdk_rs::api::node::sse::{
+        error::ClientError, metrics, types::EventType, ClientCore, SseData,
+    };
+    use eventsource_stream::Event;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    fn get_counter(entries: &[(String, DebugValue)], name: &str) -> u64 {
+        entries
+            .iter()
+            .filter(|(key, _)| key == name)
+            .map(|(_, value)| match value {
+                DebugValue::Counter(count) => *count,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    #[tokio::test]
+    async fn test_client_core_handle_event_records_metrics() {
+        let mock_server = MockSse::start().await;
+        let mut client_core = ClientCore::new(&mock_server.url()).await;
+        client_core.connect().await.unwrap();
+        client_core.add_on_event_handler(EventType::BlockAdded, Box::new(|_| ()));
+
+        let block_added = SseData::BlockAdded(serde_json::json!({ "height": 1 }));
+        mock_server.send_event(block_added).await.unwrap();
+        let event = client_core.run_once().await.unwrap().unwrap();
+        let event_bytes = event.data.len() as u64;
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        ::metrics::with_local_recorder(&recorder, || client_core.handle_event(event)).unwrap();
+
+        let entries: Vec<(String, DebugValue)> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, value)| (key.key().name().to_string(), value))
+            .collect();
+
+        assert_eq!(get_counter(&entries, metrics::EVENTS_RECEIVED), 1);
+        assert_eq!(get_counter(&entries, metrics::BYTES_RECEIVED), event_bytes);
+        assert_eq!(get_counter(&entries, metrics::EVENTS_DECODED), 1);
+        assert_eq!(get_counter(&entries, metrics::DECODE_ERRORS), 0);
+        assert!(entries
+            .iter()
+            .any(|(key, _)| key == metrics::HANDLER_DURATION_SECONDS));
+        assert!(entries
+            .iter()
+            .any(|(key, _)| key == metrics::LAST_EVENT_TIMESTAMP_SECONDS));
+    }
+
+    #[tokio::test]
+    async fn test_client_core_handle_event_records_undecodable_event() {
+        let mock_server = MockSse::start().await;
+        let mut client_core = ClientCore::new(&mock_server.url()).await;
+        let event = Event {
+            data: r#"{"BlockAdded":"#.to_string(),
+            ..Event::default()
+        };
+        let event_bytes = event.data.len() as u64;
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let result = ::metrics::with_local_recorder(&recorder, || client_core.handle_event(event));
+        assert!(matches!(result, Err(ClientError::DeserializationError(_))));
+
+        let entries: Vec<(String, DebugValue)> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, value)| (key.key().name().to_string(), value))
+            .collect();
+
+        assert_eq!(get_counter(&entries, metrics::EVENTS_RECEIVED), 1);
+        assert_eq!(get_counter(&entries, metrics::BYTES_RECEIVED), event_bytes);
+        assert_eq!(get_counter(&entries, metrics::EVENTS_DECODED), 0);
+        assert_eq!(get_counter(&entries, metrics::DECODE_ERRORS), 1);
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod utils;
+mod tests {
+    use crate::utils::MockSse;
+    use casper_sdk_rs::api::node::sse::step::{AuctionChange, EraEnded, StepError};
+    use casper_sdk_rs::api::node::sse::{client::Client, SseData};
+    use casper_types::{
+        account::AccountHash,
+        execution::{Effects, TransformKindV2, TransformV2},
+        system::auction::{BidAddr, EraInfo, SeigniorageAllocation},
+        EraId, Key, PublicKey, SecretKey, StoredValue, U512,
+    };
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    fn get_public_key(seed: u8) -> PublicKey {
+        PublicKey::from(&SecretKey::ed25519_from_bytes([seed; 32]).unwrap())
+    }
+
+    fn get_step_event(era_id: u64) -> SseData {
+        let mut era_info = EraInfo::new();
+        era_info.seigniorage_allocations_mut().extend([
+            SeigniorageAllocation::validator(get_public_key(1), U512::from(100)),
+            SeigniorageAllocation::delegator(get_public_key(3), get_public_key(1), U512::from(50)),
+            SeigniorageAllocation::validator(get_public_key(2), U512::from(10)),
+        ]);
+        let bid_addr = BidAddr::Validator(AccountHash::new([9; 32]));
+
+        let mut effects = Effects::new();
+        effects.push(TransformV2::new(
+            Key::EraSummary,
+            TransformKindV2::Write(StoredValue::EraInfo(era_info)),
+        ));
+        effects.push(TransformV2::new(
+            Key::BidAddr(bid_addr),
+            TransformKindV2::Prune(Key::BidAddr(bid_addr)),
+        ));
+        effects.push(TransformV2::new(
+            Key::Hash([4; 32]),
+            TransformKindV2::AddUInt64(1),
+        ));
+
+        SseData::Step(serde_json::json!({
+            "era_id": EraId::new(era_id),
+            "execution_effects": effects,
+        }))
+    }
+
+    #[test]
+    fn test_era_ended_from_event() {
+        let era_ended = EraEnded::from_event(&get_step_event(7)).unwrap();
+
+        assert_eq!(era_ended.era_id, EraId::new(7));
+        assert_eq!(era_ended.seigniorage_allocations.len(), 3);
+        assert_eq!(era_ended.total_rewards(), U512::from(160));
+
+        let validator_rewards = era_ended.validator_rewards();
+        assert_eq!(validator_rewards[&get_public_key(1)], U512::from(150));
+        assert_eq!(validator_rewards[&get_public_key(2)], U512::from(10));
+
+        assert_eq!(
+            era_ended.auction_changes,
+            vec![AuctionChange::BidPruned {
+                bid_addr: BidAddr::Validator(AccountHash::new([9; 32]))
+            }]
+        );
+    }
+
+    #[test]
+    fn test_era_ended_from_invalid_event() {
+        let block_added = SseData::BlockAdded(serde_json::json!({}));
+        assert!(matches!(
+            EraEnded::from_event(&block_added),
+            Err(StepError::UnexpectedEventType)
+        ));
+
+        let malformed = SseData::Step(serde_json::json!({ "era_id": "abc" }));
+        assert!(matches!(
+            EraEnded::from_event(&malformed),
+            Err(StepError::InvalidPayload(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_client_on_era_ended() {
+        let mock_server = MockSse::start().await;
+        let mut client = Client::new(&mock_server.url()).await;
+        client.connect().await.unwrap();
+
+        let (tx, mut rx) = mpsc::channel(1);
+        client
+            .on_era_ended(move |era_ended: Result<EraEnded, StepError>| {
+                tx.try_send(era_ended.unwrap()).unwrap();
+            })
+            .await
+            .unwrap();
+
+        mock_server.send_event(get_step_event(3)).await.unwrap();
+
+        let era_ended = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("Should receive era summary")
+            .unwrap();
+        assert_eq!(era_ended.era_id, EraId::new(3));
+    }
+}